@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+
+pub type Point = (i32, i32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+	pub from: Point,
+	pub to: Point,
+}
+
+impl Line {
+	pub fn is_diagonal(&self) -> bool {
+		self.from.0 != self.to.0 && self.from.1 != self.to.1
+	}
+
+	// Every point the line covers, lines are either straight or at exactly 45 degrees
+	pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+		let step = (
+			(self.to.0 - self.from.0).signum(),
+			(self.to.1 - self.from.1).signum(),
+		);
+		let length = (self.to.0 - self.from.0)
+			.abs()
+			.max((self.to.1 - self.from.1).abs());
+		(0..=length).map(move |i| (self.from.0 + step.0 * i, self.from.1 + step.1 * i))
+	}
+}
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+	type Input = Vec<Line>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		input.lines().map(parse_line).collect()
+	}
+
+	fn part_one(lines: &Self::Input) -> Result<usize> {
+		Ok(count_overlaps(lines.iter().filter(|l| !l.is_diagonal())))
+	}
+
+	fn part_two(lines: &Self::Input) -> Result<usize> {
+		Ok(count_overlaps(lines.iter()))
+	}
+}
+
+pub fn parse_line(line: &str) -> Result<Line> {
+	let (from, to) = line
+		.split_once(" -> ")
+		.ok_or_else(|| anyhow!("invalid line: {line}"))?;
+	Ok(Line {
+		from: parse_point(from)?,
+		to: parse_point(to)?,
+	})
+}
+
+fn parse_point(point: &str) -> Result<Point> {
+	let (x, y) = point
+		.split_once(',')
+		.ok_or_else(|| anyhow!("invalid point: {point}"))?;
+	Ok((x.trim().parse()?, y.trim().parse()?))
+}
+
+// Amount of points covered by at least two lines
+pub fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+	let mut covered = HashMap::<Point, usize>::new();
+	for point in lines.flat_map(Line::points) {
+		*covered.entry(point).or_default() += 1;
+	}
+	covered.values().filter(|c| **c > 1).count()
+}
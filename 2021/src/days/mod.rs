@@ -0,0 +1,24 @@
+use aoc::day::Day;
+
+pub mod day_05;
+
+/// Calls `$callback! { $crate; 5 => day_05::DayFive, "five"; ... }` with this crate and every solved
+/// day, so the registry and code generated from it (such as `aoc::example_tests!`) never go out of
+/// sync.
+#[macro_export]
+macro_rules! for_each_day {
+	($($callback:tt)*) => {
+		$($callback)*! {
+			$crate;
+			5 => day_05::DayFive, "five";
+		}
+	};
+}
+
+macro_rules! registry {
+	($krate:tt; $($day:literal => $module:ident::$solution:ident, $name:literal;)*) => {
+		pub const DAYS: &[Day] = &[$(Day::new::<$module::$solution>($day, $name)),*];
+	};
+}
+
+for_each_day!(registry);
@@ -0,0 +1,10 @@
+use aoc::Year;
+
+pub mod days;
+
+pub static YEAR: Year = Year {
+	year: 2021,
+	package: env!("CARGO_CRATE_NAME"),
+	root: env!("CARGO_MANIFEST_DIR"),
+	days: days::DAYS,
+};
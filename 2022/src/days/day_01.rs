@@ -0,0 +1,31 @@
+use anyhow::Result;
+use aoc::Solution;
+use itertools::Itertools;
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+	type Input = Vec<u32>;
+	type PartOne = u32;
+	type PartTwo = u32;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		input.lines().try_fold(vec![0], |mut res, l| {
+			if l.is_empty() {
+				res.push(0);
+			} else {
+				let last = res.len() - 1;
+				res[last] += l.parse::<u32>()?;
+			}
+			Ok(res)
+		})
+	}
+
+	fn part_one(calories: &Self::Input) -> Result<u32> {
+		Ok(calories.iter().copied().max().unwrap_or(0))
+	}
+
+	fn part_two(calories: &Self::Input) -> Result<u32> {
+		Ok(calories.iter().sorted_unstable().rev().take(3).sum())
+	}
+}
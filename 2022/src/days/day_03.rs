@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use itertools::Itertools;
+
+pub fn char_to_points(c: char) -> u64 {
+	if c > 'a' {
+		c as u64 - 'a' as u64 + 1
+	} else {
+		c as u64 - 'A' as u64 + 27
+	}
+}
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+	type Input = Vec<String>;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(input.lines().map(|l| l.trim().to_string()).collect())
+	}
+
+	fn part_one(sacks: &Self::Input) -> Result<u64> {
+		sacks
+			.iter()
+			.map(|l| l.split_at(l.len() / 2))
+			.map(|l| {
+				l.0
+					.chars()
+					.find(|c| l.1.contains(*c))
+					.ok_or_else(|| anyhow!("no shared item in {}{}", l.0, l.1))
+			})
+			.map_ok(char_to_points)
+			.sum()
+	}
+
+	fn part_two(sacks: &Self::Input) -> Result<u64> {
+		sacks
+			.iter()
+			.tuples::<(&String, &String, &String)>()
+			.map(|g| {
+				g.0
+					.chars()
+					.find(|c| g.1.contains(*c) && g.2.contains(*c))
+					.ok_or_else(|| anyhow!("no badge shared by group {g:?}"))
+			})
+			.map_ok(char_to_points)
+			.sum()
+	}
+}
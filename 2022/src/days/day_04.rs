@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Error, Result};
+use aoc::Solution;
+use itertools::Itertools;
+
+pub type Range = (u8, u8);
+
+pub fn range_converter(st: &str) -> Result<Range> {
+	st.split('-')
+		.map(|s| s.parse::<u8>())
+		.fold_ok(
+			(0, 0),
+			|res, d| if res.0 > 0 { (res.0, d) } else { (d, res.1) },
+		)
+		.map_err(Error::from)
+}
+
+pub struct DayFour;
+
+impl Solution for DayFour {
+	type Input = Vec<(Range, Range)>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		input
+			.lines()
+			.map(|l| {
+				let (start, end) = l
+					.split(',')
+					.collect_tuple()
+					.ok_or_else(|| anyhow!("invalid pair: {l}"))?;
+				Ok((range_converter(start)?, range_converter(end)?))
+			})
+			.collect()
+	}
+
+	fn part_one(pairs: &Self::Input) -> Result<usize> {
+		Ok(
+			pairs
+				.iter()
+				.filter(|((s1, e1), (s2, e2))| (s1 >= s2 && e1 <= e2) || (s2 >= s1 && e2 <= e1))
+				.count(),
+		)
+	}
+
+	fn part_two(pairs: &Self::Input) -> Result<usize> {
+		Ok(
+			pairs
+				.iter()
+				.filter(|((s1, e1), (s2, e2))| s1 <= e2 && e1 >= s2)
+				.count(),
+		)
+	}
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use aoc::Solution;
+use itertools::Itertools;
+
+// Amount of crates, source stack and target stack
+pub type Move = (usize, usize, usize);
+
+pub fn get_board(input: &str) -> Vec<Vec<char>> {
+	let mut raw_board = input
+		.lines()
+		.take_while(|l| !l.trim().is_empty())
+		.collect_vec();
+	let indices = raw_board
+		.pop()
+		.unwrap()
+		.split(' ')
+		.filter_map(|s| s.parse::<usize>().ok())
+		.map(|n| n - 1)
+		.collect_vec();
+	indices
+		.iter()
+		.map(|i| {
+			let mut vec: Vec<char> = Vec::new();
+			for line in raw_board.iter().rev() {
+				let char = line.chars().nth(4 * i + 1).unwrap();
+				if char != ' ' {
+					vec.push(char)
+				}
+			}
+			vec
+		})
+		.collect_vec()
+}
+
+pub fn get_moves(input: &str) -> Vec<Move> {
+	let lines = input
+		.lines()
+		.skip_while(|l| !l.trim().is_empty())
+		.skip(1)
+		.collect_vec();
+	lines
+		.iter()
+		.map(|l| {
+			l.split(' ')
+				.filter_map(|s| s.parse::<usize>().ok())
+				.collect_tuple()
+				.unwrap()
+		})
+		.map(|(amnt, from, to)| (amnt, from - 1, to - 1))
+		.collect_vec()
+}
+
+pub fn get_top(board: Vec<Vec<char>>) -> String {
+	board
+		.iter()
+		.map(|stack| *stack.last().unwrap())
+		.collect::<String>()
+}
+
+pub fn move_crates(board: &mut [Vec<char>], moves: &[Move], keep_order: bool) {
+	for (amount, from, to) in moves {
+		let mut amnt = *amount;
+		let mut crates = Vec::with_capacity(amnt);
+		while amnt > 0 {
+			crates.push(board[*from].pop().unwrap());
+			amnt -= 1;
+		}
+		if keep_order {
+			crates.reverse();
+		}
+
+		board[*to].extend(crates);
+	}
+}
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+	type Input = (Vec<Vec<char>>, Vec<Move>);
+	type PartOne = String;
+	type PartTwo = String;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok((get_board(input), get_moves(input)))
+	}
+
+	fn part_one((board, moves): &Self::Input) -> Result<String> {
+		let mut board = board.clone();
+		move_crates(&mut board, moves, false);
+		Ok(get_top(board))
+	}
+
+	fn part_two((board, moves): &Self::Input) -> Result<String> {
+		let mut board = board.clone();
+		move_crates(&mut board, moves, true);
+		Ok(get_top(board))
+	}
+}
@@ -0,0 +1,37 @@
+use std::{iter::Enumerate, slice::Windows};
+
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use itertools::Itertools;
+
+pub fn find_marker(mut windows: Enumerate<Windows<char>>) -> Result<usize> {
+	let w = windows
+		.find(|(_, w)| w.iter().unique().count() == w.len())
+		.ok_or_else(|| {
+			anyhow!(
+				"couldn't find marker of size {} in stream",
+				windows.next().unwrap().1.len()
+			)
+		})?;
+	Ok(w.0 + w.1.len())
+}
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+	type Input = Vec<char>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(input.trim().chars().collect_vec())
+	}
+
+	fn part_one(chars: &Self::Input) -> Result<usize> {
+		find_marker(chars.windows(4).enumerate())
+	}
+
+	fn part_two(chars: &Self::Input) -> Result<usize> {
+		find_marker(chars.windows(14).enumerate())
+	}
+}
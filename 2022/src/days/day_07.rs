@@ -0,0 +1,197 @@
+use std::{
+	cell::{Ref, RefCell},
+	rc::Rc,
+};
+
+use crate::YEAR;
+use anyhow::{anyhow, bail, Result};
+use aoc::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+
+lazy_static! {
+	static ref START_RE: Regex = Regex::new(r"(^\S+)\s").unwrap();
+	static ref CMD_RE: Regex = Regex::new(r"^\$ ([a-z]+)").unwrap();
+	static ref FILE_RE: Regex = Regex::new(r"^(?P<size>\d+) (?P<name>.*)$").unwrap();
+	static ref DIR_RE: Regex = Regex::new(r"^dir (.*)$").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub struct Directory {
+	pub parent: Option<Rc<RefCell<Directory>>>,
+	pub name: String,
+	pub size: u64,
+	pub children: Vec<Rc<RefCell<Directory>>>,
+}
+
+impl Directory {
+	pub fn get_smallest_dir_size_for_size(&self, size: u64) -> Option<u64> {
+		let mut result = None;
+		if self.size >= size {
+			result = Some(self.size);
+		}
+
+		for d in &self.children {
+			let d_result = d.borrow().get_smallest_dir_size_for_size(size);
+			if let Some(d_res) = d_result {
+				match result {
+					Some(res) => {
+						if d_res > size && res > d_res {
+							result = d_result
+						}
+					}
+					None => {
+						if d_res > size {
+							result = d_result
+						}
+					}
+				}
+			}
+		}
+		result
+	}
+}
+
+pub fn generate_dir_tree(
+	input: String,
+	parent: Option<Rc<RefCell<Directory>>>,
+) -> Result<Rc<RefCell<Directory>>> {
+	let mut lines = input.lines();
+	let mut current_dir = Rc::new(RefCell::new(Directory {
+		parent,
+		name: lines
+			.next()
+			.unwrap()
+			.split(' ')
+			.next_back()
+			.unwrap()
+			.to_string(),
+		size: 0,
+		children: vec![],
+	}));
+	for l in lines {
+		let start = START_RE.captures(l).unwrap().get(1).unwrap();
+		match start.as_str() {
+			"$" => {
+				let cmd = CMD_RE.captures(l).unwrap().get(1).unwrap();
+				match cmd.as_str() {
+					"cd" => {
+						let dir_name = l.split(' ').nth(2).unwrap();
+						if dir_name == ".." {
+							if let Some(parent) = current_dir.clone().borrow().parent.clone() {
+								current_dir = parent;
+							} else {
+								bail!("No parent directory");
+							};
+						} else {
+							let new_dir = Rc::new(RefCell::new(Directory {
+								parent: Some(current_dir.clone()),
+								name: l.split(' ').next_back().unwrap().to_string(),
+								size: 0,
+								children: vec![],
+							}));
+							current_dir.borrow_mut().children.push(new_dir.clone());
+							current_dir = new_dir;
+						}
+					}
+					"ls" => continue,
+					_ => unreachable!(),
+				}
+			}
+			"dir" => continue,
+			_ => match start.as_str().parse::<u64>() {
+				Ok(fs) => {
+					current_dir.borrow_mut().size += fs;
+					let mut parent = current_dir.borrow().parent.clone();
+					while let Some(p) = parent {
+						p.borrow_mut().size += fs;
+						parent = p.borrow().parent.clone();
+					}
+				}
+				Err(_) => unreachable!(),
+			},
+		}
+	}
+	let mut parent = current_dir;
+	while let Some(p) = parent.clone().borrow().parent.clone() {
+		parent = p;
+	}
+	Ok(parent)
+}
+
+pub fn get_total_size(dir: Ref<Directory>, dir_size_limit: u64) -> u64 {
+	let mut total = 0;
+	if dir.size <= dir_size_limit {
+		total += dir.size;
+	}
+
+	for d in dir.children.iter() {
+		total += get_total_size(d.borrow(), dir_size_limit);
+	}
+	total
+}
+
+// The sizes come from aoc.toml, which can make freeing space impossible or unnecessary
+pub fn get_space_needed(dir: Ref<Directory>, total_space: u64, space_required: u64) -> Result<u64> {
+	let Some(free) = total_space.checked_sub(dir.size) else {
+		bail!(
+			"the files take up {}, more than the total space of {total_space}",
+			dir.size
+		);
+	};
+	match space_required.checked_sub(free) {
+		Some(needed) if needed > 0 => Ok(needed),
+		_ => bail!("{free} is free already, which is at least the {space_required} required"),
+	}
+}
+
+pub fn get_smallest_directory_needed(dir: Ref<Directory>, required: u64) -> Option<u64> {
+	dir
+		.children
+		.iter()
+		.filter_map(|d| d.borrow().get_smallest_dir_size_for_size(required))
+		.min()
+}
+
+// Sizes from the puzzle, overridable under `[day.7.params]` in aoc.toml
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	pub dir_size_limit: u64,
+	pub total_space: u64,
+	pub space_required: u64,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			dir_size_limit: 100000,
+			total_space: 70000000,
+			space_required: 30000000,
+		}
+	}
+}
+
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+	type Input = (Rc<RefCell<Directory>>, Params);
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		let params = YEAR.config()?.params::<Params>(7)?;
+		Ok((generate_dir_tree(input.to_string(), None)?, params))
+	}
+
+	fn part_one((tree, params): &Self::Input) -> Result<u64> {
+		Ok(get_total_size(tree.borrow(), params.dir_size_limit))
+	}
+
+	fn part_two((tree, params): &Self::Input) -> Result<u64> {
+		let needed = get_space_needed(tree.borrow(), params.total_space, params.space_required)?;
+		get_smallest_directory_needed(tree.borrow(), needed)
+			.ok_or_else(|| anyhow!("no directory is large enough to free {needed}"))
+	}
+}
@@ -0,0 +1,169 @@
+use anyhow::Result;
+use aoc::{Grid, Implementation, Solution};
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+	type Input = Grid<u8>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(Grid::from(
+			input
+				.lines()
+				.map(|l| {
+					l.chars()
+						.map(|c| String::from(c).parse())
+						.collect::<Result<Vec<_>, _>>()
+				})
+				.collect::<Result<Vec<_>, _>>()?,
+		))
+	}
+
+	fn part_one(grid: &Self::Input) -> Result<usize> {
+		count_visible(grid)
+	}
+
+	fn part_two(grid: &Self::Input) -> Result<usize> {
+		highest_scenic_score(grid)
+	}
+
+	fn implementations() -> Vec<Implementation<Self::Input>> {
+		vec![Implementation {
+			name: "fast",
+			part: 1,
+			solve: |grid| Ok(count_visible_fast(grid).to_string()),
+		}]
+	}
+}
+
+pub fn count_visible(grid: &Grid<u8>) -> Result<usize> {
+	match grid
+		.rows()
+		.iter()
+		.enumerate()
+		.map(|(i, row)| {
+			match row
+				.iter()
+				.enumerate()
+				.map(|(j, tree)| {
+					let col = grid.col(j)?;
+					Ok(
+						(visible_left(row, j, tree)
+							|| visible_right(row, j, tree)
+							|| visible_left(col, i, tree)
+							|| visible_right(col, i, tree)) as usize,
+					)
+				})
+				.collect::<Result<Vec<usize>>>()
+			{
+				Ok(v) => Ok(v.into_iter().sum()),
+				Err(e) => Err(e),
+			}
+		})
+		.collect::<Result<Vec<usize>>>()
+	{
+		Ok(v) => Ok(v.into_iter().sum()),
+		Err(e) => Err(e),
+	}
+}
+
+// Walks every line in from both of its ends once, marking the trees taller than all trees before
+// them, instead of scanning to the edges from every tree
+pub fn count_visible_fast(grid: &Grid<u8>) -> usize {
+	let rows = grid.rows();
+	let (height, width) = (rows.len(), rows.first().map_or(0, Vec::len));
+	let mut visible = vec![vec![false; width]; height];
+	for i in 0..height {
+		mark_visible(rows, &mut visible, (0..width).map(|j| (i, j)));
+		mark_visible(rows, &mut visible, (0..width).rev().map(|j| (i, j)));
+	}
+	for j in 0..width {
+		mark_visible(rows, &mut visible, (0..height).map(|i| (i, j)));
+		mark_visible(rows, &mut visible, (0..height).rev().map(|i| (i, j)));
+	}
+	visible.iter().flatten().filter(|v| **v).count()
+}
+
+fn mark_visible(
+	rows: &[Vec<u8>],
+	visible: &mut [Vec<bool>],
+	line: impl Iterator<Item = (usize, usize)>,
+) {
+	let mut tallest = None;
+	for (i, j) in line {
+		if tallest.is_none_or(|t| rows[i][j] > t) {
+			visible[i][j] = true;
+			tallest = Some(rows[i][j]);
+		}
+	}
+}
+
+pub fn highest_scenic_score(grid: &Grid<u8>) -> Result<usize> {
+	match grid
+		.rows()
+		.iter()
+		.enumerate()
+		.map(|(i, row)| {
+			match row
+				.iter()
+				.enumerate()
+				.map(|(j, tree)| -> Result<_> {
+					let col = grid.col(j)?;
+					Ok(
+						count_left(row, j, tree)
+							* count_right(row, j, tree)
+							* count_left(col, i, tree)
+							* count_right(col, i, tree),
+					)
+				})
+				.collect::<Result<Vec<_>>>()
+			{
+				Ok(v) => Ok(v.into_iter().max().unwrap()),
+				Err(e) => Err(e),
+			}
+		})
+		.collect::<Result<Vec<_>>>()
+	{
+		Ok(v) => Ok(v.into_iter().max().unwrap()),
+		Err(e) => Err(e),
+	}
+}
+
+pub fn visible_left(line: &[u8], idx: usize, tree: &u8) -> bool {
+	line.iter().take(idx).all(|t| *t < *tree)
+}
+
+pub fn visible_right(line: &[u8], idx: usize, tree: &u8) -> bool {
+	line.iter().skip(idx + 1).all(|t| *t < *tree)
+}
+
+pub fn count_left(line: &[u8], idx: usize, tree: &u8) -> usize {
+	if idx != 0 {
+		line
+			.iter()
+			.enumerate()
+			.take(idx)
+			.rev()
+			.take_while(|(i, t)| **t < *tree && *i != 0)
+			.count()
+			+ 1
+	} else {
+		0
+	}
+}
+
+pub fn count_right(line: &[u8], idx: usize, tree: &u8) -> usize {
+	if idx != line.len() - 1 {
+		line
+			.iter()
+			.enumerate()
+			.skip(idx + 1)
+			.take_while(|(i, t)| **t < *tree && *i != line.len() - 1)
+			.count()
+			+ 1
+	} else {
+		0
+	}
+}
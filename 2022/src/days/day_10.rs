@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use aoc::Solution;
+use tracing::trace_span;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+	Noop,
+	AddX(i32),
+}
+
+pub struct Crt(pub [[bool; 40]; 6]);
+
+impl Display for Crt {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (i, row) in self.0.iter().enumerate() {
+			if i > 0 {
+				writeln!(f)?;
+			}
+			for pixel in row {
+				write!(f, "{}", if *pixel { '#' } else { '.' })?;
+			}
+		}
+		Ok(())
+	}
+}
+
+pub struct DayTen;
+
+impl Solution for DayTen {
+	type Input = Vec<Instruction>;
+	type PartOne = i32;
+	type PartTwo = Crt;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		input
+			.lines()
+			.map(|l| {
+				Ok(match l.split(' ').next().unwrap_or("") {
+					"noop" => Instruction::Noop,
+					"addx" => Instruction::AddX(l.split(' ').next_back().unwrap().parse::<i32>()?),
+					_ => bail!("Invalid line"),
+				})
+			})
+			.collect()
+	}
+
+	fn part_one(program: &Self::Input) -> Result<i32> {
+		Ok(execute(program).0)
+	}
+
+	fn part_two(program: &Self::Input) -> Result<Crt> {
+		Ok(execute(program).1)
+	}
+}
+
+pub fn execute(program: &[Instruction]) -> (i32, Crt) {
+	let mut cycle: u32 = 0;
+	let mut register: i32 = 1;
+	let mut signal_strengths: i32 = 0;
+	let mut crt: [[bool; 40]; 6] = [[false; 40]; 6];
+	// The first pixel is drawn before any instruction finishes
+	draw_pixel(cycle, register, &mut crt);
+	for instruction in program {
+		match instruction {
+			Instruction::Noop => {
+				cycle = clock_cycle(cycle, register, &mut signal_strengths, &mut crt);
+			}
+			Instruction::AddX(x) => {
+				cycle = clock_cycle(cycle, register, &mut signal_strengths, &mut crt);
+				register += x;
+				cycle = clock_cycle(cycle, register, &mut signal_strengths, &mut crt);
+			}
+		};
+	}
+	(signal_strengths, Crt(crt))
+}
+
+pub fn clock_cycle(
+	cycle: u32,
+	register: i32,
+	signal_strengths: &mut i32,
+	crt: &mut [[bool; 40]; 6],
+) -> u32 {
+	let current_cycle = cycle + 1;
+	let _span = trace_span!("cycle", cycle = current_cycle).entered();
+
+	// Part one
+	if (current_cycle + 1) % 40 == 20 {
+		*signal_strengths += register * (current_cycle + 1) as i32;
+	}
+
+	// Part two, the cycle after the last pixel would wrap around and overwrite the first one
+	if current_cycle < 240 {
+		draw_pixel(current_cycle, register, crt);
+	}
+	current_cycle
+}
+
+fn draw_pixel(cycle: u32, register: i32, crt: &mut [[bool; 40]; 6]) {
+	let idx = crt_index(cycle);
+	crt[idx.0][idx.1] = (register - 1..=register + 1).contains(&(idx.1 as i32));
+}
+
+#[inline]
+pub fn crt_index(cycle: u32) -> (usize, usize) {
+	(cycle as usize / 40 % 6, cycle as usize % 40)
+}
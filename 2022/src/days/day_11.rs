@@ -0,0 +1,162 @@
+use crate::YEAR;
+use anyhow::Result;
+use aoc::Solution;
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{collections::VecDeque, default::Default};
+use tracing::trace_span;
+
+#[derive(Default, Debug, Clone)]
+pub enum Op {
+	Add(u64),
+	Mul(u64),
+	Pow,
+	#[default]
+	Unset,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Monkey {
+	pub items: VecDeque<u64>,
+	pub op: Op,
+	pub test: u64,
+	pub test_true: usize,
+	pub test_false: usize,
+	pub inspected: usize,
+}
+
+// Round counts from the puzzle, overridable under `[day.11.params]` in aoc.toml
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+	pub part_one_rounds: usize,
+	pub part_two_rounds: usize,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			part_one_rounds: 20,
+			part_two_rounds: 10000,
+		}
+	}
+}
+
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+	type Input = (Vec<Monkey>, Params);
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		let params = YEAR.config()?.params::<Params>(11)?;
+		Ok((parse_monkeys(input), params))
+	}
+
+	fn part_one((monkeys, params): &Self::Input) -> Result<usize> {
+		Ok(calculate(monkeys.clone(), params.part_one_rounds, true))
+	}
+
+	fn part_two((monkeys, params): &Self::Input) -> Result<usize> {
+		Ok(calculate(monkeys.clone(), params.part_two_rounds, false))
+	}
+}
+
+pub fn parse_monkeys(input: &str) -> Vec<Monkey> {
+	input
+		.split("\n\n")
+		.map(|s| {
+			s.lines().skip(1).fold(Monkey::default(), |res, line| {
+				let mut res = res;
+				match line
+					.trim_start()
+					.chars()
+					.take_while(|c| *c != ':')
+					.collect::<String>()
+					.as_str()
+				{
+					"Starting items" => {
+						res.items = line
+							.trim()
+							.replace("Starting items: ", "")
+							.split(", ")
+							.map(|s| s.trim().parse::<u64>().unwrap())
+							.collect()
+					}
+					"Operation" => {
+						let op_str = line.trim().replace("Operation: ", "");
+						let mut op_str = op_str.split(' ');
+						let last = op_str.clone().next_back().unwrap();
+						match op_str.nth(3).unwrap() {
+							"*" => {
+								res.op = if last == "old" {
+									Op::Pow
+								} else {
+									Op::Mul(last.parse::<u64>().unwrap())
+								};
+							}
+							"+" => res.op = Op::Add(last.parse::<u64>().unwrap()),
+							_ => (),
+						}
+					}
+					"Test" => {
+						res.test = line.split(' ').next_back().unwrap().parse::<u64>().unwrap();
+					}
+					x if x.starts_with("If ") => {
+						let monkey_idx = line
+							.split(' ')
+							.next_back()
+							.unwrap()
+							.parse::<usize>()
+							.unwrap();
+						if x.ends_with("true") {
+							res.test_true = monkey_idx;
+						} else {
+							res.test_false = monkey_idx
+						}
+					}
+					_ => (),
+				};
+				res
+			})
+		})
+		.collect_vec()
+}
+
+pub fn calculate(mut monkeys: Vec<Monkey>, rounds: usize, divide: bool) -> usize {
+	let mo = monkeys.iter().map(|m| m.test).product::<u64>();
+	for round in 0..rounds {
+		let _span = trace_span!("round", round).entered();
+		for m_idx in 0..monkeys.len() {
+			let monkey = monkeys[m_idx].to_owned();
+			monkey.items.into_iter().for_each(|level| {
+				let mut worry_level = match monkey.op {
+					Op::Pow => (level % mo) * (level % mo) % mo,
+					Op::Add(i) => (level + i) % mo,
+					Op::Mul(i) => (level % mo) * (i % mo) % mo,
+					_ => 1,
+				};
+				if divide {
+					worry_level /= 3;
+				}
+				if (worry_level % monkey.test) == 0 {
+					monkeys[monkey.test_true].items.push_back(worry_level);
+				} else {
+					monkeys[monkey.test_false].items.push_back(worry_level);
+				}
+			});
+
+			let monkey = monkeys.get_mut(m_idx).unwrap();
+			monkey.inspected += monkey.items.len();
+			monkey.items = VecDeque::new();
+		}
+	}
+	monkeys
+		.iter()
+		.map(|m| m.inspected)
+		.sorted()
+		.rev()
+		.take(2)
+		.product::<usize>()
+}
@@ -0,0 +1,50 @@
+use aoc::day::Day;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+
+/// Calls `$callback! { $crate; 1 => day_01::DayOne, "one"; ... }` with this crate and every solved
+/// day, so the registry and code generated from it (such as `aoc::example_tests!`) never go out of
+/// sync.
+#[macro_export]
+macro_rules! for_each_day {
+	($($callback:tt)*) => {
+		$($callback)*! {
+			$crate;
+			1 => day_01::DayOne, "one";
+			2 => day_02::DayTwo, "two";
+			3 => day_03::DayThree, "three";
+			4 => day_04::DayFour, "four";
+			5 => day_05::DayFive, "five";
+			6 => day_06::DaySix, "six";
+			7 => day_07::DaySeven, "seven";
+			8 => day_08::DayEight, "eight";
+			9 => day_09::DayNine, "nine";
+			10 => day_10::DayTen, "ten";
+			11 => day_11::DayEleven, "eleven";
+			12 => day_12::DayTwelve, "twelve";
+			13 => day_13::DayThirteen, "thirteen";
+			14 => day_14::DayFourteen, "fourteen";
+		}
+	};
+}
+
+macro_rules! registry {
+	($krate:tt; $($day:literal => $module:ident::$solution:ident, $name:literal;)*) => {
+		pub const DAYS: &[Day] = &[$(Day::new::<$module::$solution>($day, $name)),*];
+	};
+}
+
+for_each_day!(registry);
@@ -0,0 +1,104 @@
+use std::{env, fs, time::Duration};
+
+use advent_of_code_2022::{
+	days::{day_07, day_11},
+	YEAR,
+};
+use aoc::config::Config;
+
+#[test]
+fn reads_per_day_overrides() {
+	let config = Config::parse(
+		"year = 2021\n\
+		[day.7]\n\
+		input = \"other/day_seven\"\n\
+		[day.7.params]\n\
+		total_space = 100\n",
+		&YEAR,
+	)
+	.unwrap();
+
+	assert_eq!(config.year(), 2021);
+	assert_eq!(config.bench.iterations, 20);
+	assert_eq!(config.input_dir(), YEAR.root().join("input"));
+	assert_eq!(config.answers_path(), YEAR.root().join("answers/2021.toml"));
+	assert_eq!(
+		config.day_input(7),
+		Some(YEAR.root().join("other/day_seven"))
+	);
+	assert_eq!(config.day_input(8), None);
+	let params = config.params::<day_07::Params>(7).unwrap();
+	assert_eq!(params.total_space, 100);
+	assert_eq!(params.space_required, 30000000);
+	assert_eq!(
+		config.params::<day_11::Params>(11).unwrap().part_two_rounds,
+		10000
+	);
+}
+
+#[test]
+fn rejects_unknown_keys() {
+	for content in [
+		"yaer = 2022\n",
+		"[bench]\nruns = 5\n",
+		"[day.7]\nparams = {}\nimput = \"day_seven\"\n",
+		"[day.26]\ninput = \"day_twenty_six\"\n",
+	] {
+		assert!(
+			Config::parse(content, &YEAR).is_err(),
+			"accepted {content:?}"
+		);
+	}
+
+	let config = Config::parse("[day.11.params]\nrounds = 5\n", &YEAR).unwrap();
+	let err = config.params::<day_11::Params>(11).unwrap_err();
+	assert!(format!("{err:#}").contains("unknown field `rounds`"));
+}
+
+#[test]
+fn reads_limits() {
+	let config = Config::parse("[limits]\ntimeout = 2.5\nmemory = 64\n", &YEAR).unwrap();
+	let limits = config.limits.limits().unwrap();
+	assert_eq!(limits.timeout, Some(Duration::from_millis(2500)));
+	assert_eq!(limits.memory, Some(64 << 20));
+	assert!(!Config::parse("", &YEAR)
+		.unwrap()
+		.limits
+		.limits()
+		.unwrap()
+		.is_limited());
+
+	for content in [
+		"[limits]\ntimeout = -1\n",
+		"[limits]\ntimeout = 0\n",
+		"[limits]\nstack = 8\n",
+	] {
+		assert!(
+			Config::parse(content, &YEAR).is_err(),
+			"accepted {content:?}"
+		);
+	}
+}
+
+#[test]
+fn only_applies_overrides_to_their_year() {
+	let path = env::temp_dir().join(format!("aoc-config-{}.toml", std::process::id()));
+	fs::write(&path, "year = 2022\nexample = 2\n").unwrap();
+	let config = Config::load_override(&path, &YEAR).unwrap().unwrap();
+	assert_eq!(config.example, 2);
+	assert_eq!(config.input_dir(), env::temp_dir().join("input"));
+
+	fs::write(&path, "year = 2021\nexample = 2\n").unwrap();
+	assert!(Config::load_override(&path, &YEAR).unwrap().is_none());
+
+	fs::write(&path, "example = 2\n").unwrap();
+	let error = Config::load_override(&path, &YEAR).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		format!(
+			"{} doesn't say which year it configures, set `year` in it to use it as AOC_CONFIG",
+			path.display()
+		)
+	);
+	fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,31 @@
+use advent_of_code_2022::days::day_07::{self, DaySeven};
+use aoc::Solution;
+
+#[test]
+fn rejects_sizes_that_leave_nothing_to_solve() {
+	let (tree, params) = DaySeven::parse(include_str!("../input/examples/day_seven_1")).unwrap();
+	let needed = |total_space, space_required| {
+		day_07::get_space_needed(tree.borrow(), total_space, space_required).map_err(|e| e.to_string())
+	};
+	// The files of the example take up 48381165
+	assert_eq!(
+		needed(params.total_space, params.space_required),
+		Ok(8381165)
+	);
+	assert_eq!(
+		needed(40000000, 30000000),
+		Err(String::from(
+			"the files take up 48381165, more than the total space of 40000000"
+		))
+	);
+	assert_eq!(
+		needed(90000000, 30000000),
+		Err(String::from(
+			"41618835 is free already, which is at least the 30000000 required"
+		))
+	);
+	assert_eq!(
+		day_07::get_smallest_directory_needed(tree.borrow(), 48381165),
+		None
+	);
+}
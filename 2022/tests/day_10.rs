@@ -0,0 +1,13 @@
+use advent_of_code_2022::days::day_10::DayTen;
+use aoc::Solution;
+
+#[test]
+fn draws_the_first_pixel_before_the_first_instruction() {
+	// 240 cycles, the register only moves away from the first pixel in the very last one
+	let program = "noop\n".repeat(238) + "addx 10\n";
+	let crt = DayTen::part_two(&DayTen::parse(&program).unwrap())
+		.unwrap()
+		.to_string();
+	let row = String::from("###") + &".".repeat(37);
+	assert_eq!(crt, vec![row; 6].join("\n"));
+}
@@ -0,0 +1 @@
+aoc::example_tests!(advent_of_code_2022);
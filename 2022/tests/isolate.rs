@@ -0,0 +1,87 @@
+use std::{path::Path, time::Duration};
+
+use aoc::{
+	day::DEFAULT_IMPL,
+	isolate::{self, Limits, Outcome},
+};
+
+fn run(binary: &str, input: &str, limits: Limits) -> Outcome {
+	isolate::run(Path::new(binary), input, DEFAULT_IMPL, None, &limits).unwrap()
+}
+
+#[test]
+fn solves_in_child_process() {
+	let input = include_str!("../input/examples/day_one_1");
+	let Outcome::Solved(entries) = run(env!("CARGO_BIN_EXE_day_one"), input, Limits::default())
+	else {
+		panic!("day one didn't solve its example");
+	};
+	let answers = entries
+		.iter()
+		.map(|e| e.answer.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(answers, ["24000", "45000"]);
+}
+
+#[test]
+fn kills_days_that_run_too_long() {
+	// There's no way from S to E
+	let limits = Limits {
+		timeout: Some(Duration::from_millis(500)),
+		memory: None,
+	};
+	assert_eq!(
+		run(env!("CARGO_BIN_EXE_day_twelve"), "Sbc\nxxE\n", limits),
+		Outcome::Timeout(Duration::from_millis(500))
+	);
+}
+
+#[cfg(unix)]
+#[test]
+fn reports_running_out_of_memory() {
+	let limits = Limits {
+		timeout: Some(Duration::from_secs(20)),
+		memory: Some(256 << 20),
+	};
+	let input = "498,4 -> 498,6\n100000000,9 -> 100000000,100000000\n";
+	assert_eq!(
+		run(env!("CARGO_BIN_EXE_day_fourteen"), input, limits),
+		Outcome::OutOfMemory
+	);
+}
+
+#[test]
+fn separates_panics_from_errors() {
+	match run(env!("CARGO_BIN_EXE_day_six"), "\n", Limits::default()) {
+		Outcome::Panic(message) => assert!(message.contains("day_06.rs"), "{message}"),
+		outcome => panic!("expected a panic, got {outcome:?}"),
+	}
+	assert_eq!(
+		run(
+			env!("CARGO_BIN_EXE_day_one"),
+			"garbage\n",
+			Limits::default()
+		),
+		Outcome::Failed(String::from("invalid digit found in string"))
+	);
+}
+
+#[test]
+fn solves_only_the_requested_part() {
+	// Too short for the marker of part two, which panics looking for it
+	let binary = Path::new(env!("CARGO_BIN_EXE_day_six"));
+	let limits = Limits::default();
+	let outcome = isolate::run(binary, "abcdefg\n", DEFAULT_IMPL, Some(1), &limits).unwrap();
+	let Outcome::Solved(entries) = outcome else {
+		panic!("part one of day six failed: {outcome:?}");
+	};
+	let answers = entries
+		.iter()
+		.map(|e| (e.part, e.answer.as_str()))
+		.collect::<Vec<_>>();
+	assert_eq!(answers, [(1, "4")]);
+	assert!(matches!(
+		isolate::run(binary, "abcdefg\n", DEFAULT_IMPL, None, &limits).unwrap(),
+		Outcome::Panic(_)
+	));
+}
@@ -0,0 +1,109 @@
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	hint,
+	sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed},
+};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+// Nothing is counted until `enable` is called, so the allocator costs a single load otherwise
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+// Signed, since memory allocated before counting started may be freed afterwards
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// A global allocator that counts allocations on top of the system allocator. Binaries opt in with
+/// `#[global_allocator] static ALLOC: Counting = Counting;` and then [`enable`].
+pub struct Counting;
+
+/// Allocations made while running a single phase of a day. Counts are process wide, so they also
+/// include any other threads that allocate at the same time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+	// Including reallocations
+	pub allocations: u64,
+	// Requested by all allocations, a reallocation counts its new size
+	pub bytes: u64,
+	// Highest number of bytes allocated at once on top of what was live when the phase started
+	pub peak_bytes: u64,
+}
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() && ENABLED.load(Relaxed) {
+			record(layout.size(), layout.size() as i64);
+		}
+		ptr
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc_zeroed(layout);
+		if !ptr.is_null() && ENABLED.load(Relaxed) {
+			record(layout.size(), layout.size() as i64);
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		if ENABLED.load(Relaxed) {
+			LIVE.fetch_sub(layout.size() as i64, Relaxed);
+		}
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new = System.realloc(ptr, layout, new_size);
+		if !new.is_null() && ENABLED.load(Relaxed) {
+			record(new_size, new_size as i64 - layout.size() as i64);
+		}
+		new
+	}
+}
+
+fn record(bytes: usize, growth: i64) {
+	ALLOCATIONS.fetch_add(1, Relaxed);
+	ALLOCATED.fetch_add(bytes as u64, Relaxed);
+	let live = LIVE.fetch_add(growth, Relaxed) + growth;
+	PEAK.fetch_max(live, Relaxed);
+}
+
+/// Starts counting allocations, failing if the running binary doesn't use [`Counting`] as its
+/// global allocator.
+pub fn enable() -> Result<()> {
+	ENABLED.store(true, Relaxed);
+	let before = ALLOCATIONS.load(Relaxed);
+	drop(hint::black_box(Box::new(0u64)));
+	if ALLOCATIONS.load(Relaxed) == before {
+		ENABLED.store(false, Relaxed);
+		bail!(
+			"this binary doesn't count allocations, its global allocator isn't aoc::allocs::Counting"
+		);
+	}
+	Ok(())
+}
+
+pub fn is_enabled() -> bool {
+	ENABLED.load(Relaxed)
+}
+
+/// Runs `f`, returning the allocations it made if counting is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+	if !is_enabled() {
+		return (f(), None);
+	}
+	let allocations = ALLOCATIONS.load(Relaxed);
+	let allocated = ALLOCATED.load(Relaxed);
+	let live = LIVE.load(Relaxed);
+	PEAK.store(live, Relaxed);
+	let result = f();
+	let stats = Stats {
+		allocations: ALLOCATIONS.load(Relaxed) - allocations,
+		bytes: ALLOCATED.load(Relaxed) - allocated,
+		peak_bytes: (PEAK.load(Relaxed) - live).max(0) as u64,
+	};
+	(result, Some(stats))
+}
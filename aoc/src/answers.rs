@@ -0,0 +1,105 @@
+use std::{fmt::Display, fs, io::ErrorKind, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+	pub day: u8,
+	pub part: u8,
+	pub input: String,
+	pub answer: String,
+}
+
+// An answer that was submitted and judged wrong, kept so it's never submitted again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+	pub day: u8,
+	pub part: u8,
+	pub input: String,
+	pub answer: String,
+	pub outcome: String,
+}
+
+// Accepted answers, stored as an array of `[[answer]]` tables so multi-line answers stay readable
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Answers {
+	#[serde(default, rename = "answer")]
+	entries: Vec<Answer>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	rejected: Vec<Rejected>,
+}
+
+impl Answers {
+	pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		match fs::read_to_string(path) {
+			Ok(content) => {
+				toml::from_str(&content).with_context(|| format!("invalid answers file {}", path.display()))
+			}
+			Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+			Err(e) => Err(e).with_context(|| format!("couldn't read {}", path.display())),
+		}
+	}
+
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::write(path, toml::to_string_pretty(self)?)
+			.with_context(|| format!("couldn't write {}", path.display()))
+	}
+
+	pub fn entries(&self) -> &[Answer] {
+		&self.entries
+	}
+
+	pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+		self
+			.entries
+			.iter()
+			.find(|a| a.day == day && a.part == part && a.input == input)
+			.map(|a| a.answer.as_str())
+	}
+
+	pub fn set(&mut self, day: u8, part: u8, input: &str, answer: String) {
+		match self
+			.entries
+			.iter_mut()
+			.find(|a| a.day == day && a.part == part && a.input == input)
+		{
+			Some(entry) => entry.answer = answer,
+			None => {
+				self.entries.push(Answer {
+					day,
+					part,
+					input: input.to_string(),
+					answer,
+				});
+				self
+					.entries
+					.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+			}
+		}
+	}
+
+	pub fn rejection(&self, day: u8, part: u8, input: &str, answer: &str) -> Option<&Rejected> {
+		self
+			.rejected
+			.iter()
+			.find(|r| r.day == day && r.part == part && r.input == input && r.answer == answer)
+	}
+
+	pub fn reject(&mut self, day: u8, part: u8, input: &str, answer: String, outcome: impl Display) {
+		if self.rejection(day, part, input, &answer).is_none() {
+			self.rejected.push(Rejected {
+				day,
+				part,
+				input: input.to_string(),
+				answer,
+				outcome: outcome.to_string(),
+			});
+		}
+	}
+}
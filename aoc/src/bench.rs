@@ -0,0 +1,104 @@
+use std::{
+	hint::black_box,
+	time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::day::Day;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+	#[serde(rename = "min_ns", serialize_with = "as_nanos")]
+	pub min: Duration,
+	#[serde(rename = "median_ns", serialize_with = "as_nanos")]
+	pub median: Duration,
+	#[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+	pub mean: Duration,
+	#[serde(rename = "stddev_ns", serialize_with = "as_nanos")]
+	pub stddev: Duration,
+}
+
+impl Stats {
+	pub fn from_samples(samples: &[Duration]) -> Self {
+		let mut sorted = samples.to_vec();
+		sorted.sort_unstable();
+		let nanos = sorted
+			.iter()
+			.map(|s| s.as_nanos() as f64)
+			.collect::<Vec<_>>();
+		let mean = nanos.iter().sum::<f64>() / nanos.len().max(1) as f64;
+		let variance =
+			nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len().max(1) as f64;
+		Self {
+			min: sorted.first().copied().unwrap_or_default(),
+			median: match sorted.len() {
+				0 => Duration::ZERO,
+				len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+				len => sorted[len / 2],
+			},
+			mean: Duration::from_nanos(mean as u64),
+			stddev: Duration::from_nanos(variance.sqrt() as u64),
+		}
+	}
+}
+
+pub(crate) fn as_nanos<S: serde::Serializer>(
+	duration: &Duration,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+pub(crate) fn from_nanos<'de, D: serde::Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Duration, D::Error> {
+	u64::deserialize(deserializer).map(Duration::from_nanos)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayBench {
+	pub day: u8,
+	pub parse: Stats,
+	pub part_one: Stats,
+	pub part_two: Stats,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+	pub iterations: usize,
+	pub warmup: usize,
+	pub days: Vec<DayBench>,
+}
+
+/// Times parsing and both parts of `day` separately, after `warmup` untimed runs of each.
+pub fn bench_day(day: &Day, input: &str, iterations: usize, warmup: usize) -> Result<DayBench> {
+	let parse = measure(iterations, warmup, || day.parse(input).map(black_box))?;
+	let parsed = day.parse(input)?;
+	let part_one = measure(iterations, warmup, || day.part_one(&parsed).map(black_box))?;
+	let part_two = measure(iterations, warmup, || day.part_two(&parsed).map(black_box))?;
+	Ok(DayBench {
+		day: day.day,
+		parse,
+		part_one,
+		part_two,
+	})
+}
+
+fn measure<T, FN>(iterations: usize, warmup: usize, mut step: FN) -> Result<Stats>
+where
+	FN: FnMut() -> Result<T>,
+{
+	for _ in 0..warmup {
+		step()?;
+	}
+	let samples = (0..iterations.max(1))
+		.map(|_| {
+			let start = Instant::now();
+			step()?;
+			Ok(start.elapsed())
+		})
+		.collect::<Result<Vec<_>>>()?;
+	Ok(Stats::from_samples(&samples))
+}
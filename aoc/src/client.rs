@@ -0,0 +1,117 @@
+use std::{
+	env, fs,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::config::Config;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!(
+	"github.com/jonahgoldwastaken/advent-of-code/",
+	env!("CARGO_PKG_VERSION")
+);
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub struct Client {
+	agent: ureq::Agent,
+	base_url: String,
+	session: String,
+	pub(crate) year: u16,
+}
+
+impl Client {
+	pub fn new(base_url: impl Into<String>, session: impl Into<String>, year: u16) -> Self {
+		Self {
+			agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+			base_url: base_url.into().trim_end_matches('/').to_string(),
+			session: session.into(),
+			year,
+		}
+	}
+
+	/// Uses `AOC_BASE_URL` if set, the session token from [`session_token`] and the year of
+	/// `config`.
+	pub fn from_env(config: &Config) -> Result<Self> {
+		let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+		Ok(Self::new(base_url, session_token(config)?, config.year()))
+	}
+
+	pub(crate) fn get(&self, path: &str) -> Result<String> {
+		let url = format!("{}{path}", self.base_url);
+		read_response(&url, self.request("GET", &url).call())
+	}
+
+	pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+		let url = format!("{}{path}", self.base_url);
+		read_response(&url, self.request("POST", &url).send_form(form))
+	}
+
+	fn request(&self, method: &str, url: &str) -> ureq::Request {
+		self
+			.agent
+			.request(method, url)
+			.set("Cookie", &format!("session={}", self.session))
+	}
+
+	pub fn fetch_input(&self, day: u8) -> Result<String> {
+		self.get(&format!("/{}/day/{day}/input", self.year))
+	}
+
+	/// Returns the input at `path` if it was downloaded before, otherwise fetches and stores it there.
+	/// An empty file, as left by `aoc new`, hasn't been downloaded yet.
+	pub fn cached_input(&self, day: u8, path: impl AsRef<Path>) -> Result<String> {
+		let path = path.as_ref();
+		match fs::read_to_string(path) {
+			Ok(input) if !input.is_empty() => return Ok(input),
+			Ok(_) => (),
+			Err(e) if e.kind() == ErrorKind::NotFound => (),
+			Err(e) => return Err(e).with_context(|| format!("couldn't read {}", path.display())),
+		}
+		let input = self.fetch_input(day)?;
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::write(path, &input).with_context(|| format!("couldn't write {}", path.display()))?;
+		Ok(input)
+	}
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+	match response {
+		Ok(response) => Ok(response.into_string()?),
+		Err(ureq::Error::Status(404, _)) => bail!("{url} isn't available (yet)"),
+		Err(ureq::Error::Status(400 | 401 | 500, _)) => {
+			bail!("{url} rejected the session token, log in again and update it")
+		}
+		Err(e) => Err(e).with_context(|| format!("couldn't fetch {url}")),
+	}
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to the `session_file` in aoc.toml or
+/// `~/.config/aoc/session`.
+pub fn session_token(config: &Config) -> Result<String> {
+	if let Ok(token) = env::var(SESSION_VAR) {
+		return Ok(token.trim().to_string());
+	}
+	let path = session_file(config)?;
+	let token = fs::read_to_string(&path).with_context(|| {
+		format!(
+			"no session token, set {SESSION_VAR} or write it to {}",
+			path.display()
+		)
+	})?;
+	Ok(token.trim().to_string())
+}
+
+fn session_file(config: &Config) -> Result<PathBuf> {
+	if let Some(file) = config.session_file() {
+		return Ok(file);
+	}
+	let home = env::var_os("HOME").ok_or_else(|| anyhow!("HOME isn't set"))?;
+	Ok(PathBuf::from(home).join(".config/aoc/session"))
+}
@@ -0,0 +1,234 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	env, fs,
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+	isolate::{self, Limits},
+	Year,
+};
+
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+lazy_static! {
+	// Loaded configurations by year, leaked so days can hold on to their parameters
+	static ref CONFIGS: Mutex<HashMap<u16, &'static Config>> = Mutex::default();
+}
+
+/// Settings from a year's `aoc.toml`. Every key is optional, and relative paths are relative to
+/// the file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+	year: Option<u16>,
+	input_dir: Option<PathBuf>,
+	answers: Option<PathBuf>,
+	progress: Option<PathBuf>,
+	session_file: Option<PathBuf>,
+	/// Private leaderboard shown by `aoc leaderboard` when no id is given
+	pub leaderboard: Option<u64>,
+	/// Example used by `--example` when no number is given
+	pub example: u8,
+	pub bench: BenchConfig,
+	pub limits: LimitsConfig,
+	#[serde(rename = "day")]
+	days: BTreeMap<String, DayConfig>,
+	#[serde(skip)]
+	root: PathBuf,
+	// The year of the crate the file belongs to
+	#[serde(skip)]
+	owner: u16,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+	pub iterations: usize,
+	pub warmup: usize,
+}
+
+// Limits of days run in their own process, unlimited unless set
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+	// Seconds
+	pub timeout: Option<f64>,
+	// MiB of address space
+	pub memory: Option<u64>,
+}
+
+// Overrides for a single day, under `[day.N]`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct DayConfig {
+	input: Option<PathBuf>,
+	// Checked by the day itself, since only it knows which parameters it takes
+	params: toml::value::Table,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			year: None,
+			input_dir: None,
+			answers: None,
+			progress: None,
+			session_file: None,
+			leaderboard: None,
+			example: 1,
+			bench: BenchConfig::default(),
+			limits: LimitsConfig::default(),
+			days: BTreeMap::new(),
+			root: PathBuf::new(),
+			owner: 0,
+		}
+	}
+}
+
+impl Default for BenchConfig {
+	fn default() -> Self {
+		Self {
+			iterations: 20,
+			warmup: 3,
+		}
+	}
+}
+
+impl LimitsConfig {
+	pub fn limits(&self) -> Result<Limits> {
+		Ok(Limits {
+			timeout: self.timeout.map(isolate::timeout).transpose()?,
+			memory: self.memory.map(|mib| mib << 20),
+		})
+	}
+}
+
+impl Config {
+	pub fn load(path: impl AsRef<Path>, year: &Year) -> Result<Self> {
+		let path = path.as_ref();
+		let content =
+			fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))?;
+		let mut config =
+			Self::parse(&content, year).with_context(|| format!("invalid {}", path.display()))?;
+		config.root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+		Ok(config)
+	}
+
+	/// Parses the configuration of `year`, with paths relative to the year's crate.
+	pub fn parse(content: &str, year: &Year) -> Result<Self> {
+		let mut config: Self = toml::from_str(content)?;
+		for day in config.days.keys() {
+			match day.parse::<u8>() {
+				Ok(1..=25) => (),
+				_ => bail!("unknown day '{day}', expected [day.N] with N between 1 and 25"),
+			}
+		}
+		if config.example == 0 {
+			bail!("examples are numbered from 1");
+		}
+		config.limits.limits().context("invalid [limits]")?;
+		config.root = year.root().to_path_buf();
+		config.owner = year.year;
+		Ok(config)
+	}
+
+	/// Loads the file at `path` if it's the configuration of `year`, which it has to name with its
+	/// `year` key. `AOC_CONFIG` is seen by every year, so it only applies to the one it names.
+	pub fn load_override(path: impl AsRef<Path>, year: &Year) -> Result<Option<Self>> {
+		let path = path.as_ref();
+		let config = Self::load(path, year)?;
+		match config.year {
+			Some(of) => Ok((of == year.year).then_some(config)),
+			None => bail!(
+				"{} doesn't say which year it configures, set `year` in it to use it as {CONFIG_VAR}",
+				path.display()
+			),
+		}
+	}
+
+	/// Loads the file in `AOC_CONFIG` if it configures `year`, otherwise the year crate's own
+	/// aoc.toml, or the defaults if it has none.
+	fn find(year: &Year) -> Result<Self> {
+		if let Some(path) = env::var_os(CONFIG_VAR) {
+			if let Some(config) = Self::load_override(path, year)? {
+				return Ok(config);
+			}
+		}
+		let path = year.root().join(CONFIG_FILE);
+		if path.is_file() {
+			Self::load(path, year)
+		} else {
+			Ok(Self {
+				root: year.root().to_path_buf(),
+				owner: year.year,
+				..Self::default()
+			})
+		}
+	}
+
+	/// Year of the puzzles, as used in the URLs of the website.
+	pub fn year(&self) -> u16 {
+		self.year.unwrap_or(self.owner)
+	}
+
+	fn resolve(&self, path: &Path) -> PathBuf {
+		self.root.join(path)
+	}
+
+	pub fn input_dir(&self) -> PathBuf {
+		self.resolve(self.input_dir.as_deref().unwrap_or(Path::new("input")))
+	}
+
+	pub fn answers_path(&self) -> PathBuf {
+		match &self.answers {
+			Some(answers) => self.resolve(answers),
+			None => self.resolve(&PathBuf::from(format!("answers/{}.toml", self.year()))),
+		}
+	}
+
+	pub fn progress_path(&self) -> PathBuf {
+		self.resolve(
+			self
+				.progress
+				.as_deref()
+				.unwrap_or(Path::new("progress.toml")),
+		)
+	}
+
+	pub fn session_file(&self) -> Option<PathBuf> {
+		self.session_file.as_deref().map(|file| self.resolve(file))
+	}
+
+	/// Input file of `day` when it doesn't live in the input directory.
+	pub fn day_input(&self, day: u8) -> Option<PathBuf> {
+		let input = self.days.get(&day.to_string())?.input.as_deref()?;
+		Some(self.resolve(input))
+	}
+
+	/// Solver parameters of `day`, with defaults for any that aren't set.
+	pub fn params<P: DeserializeOwned + Default>(&self, day: u8) -> Result<P> {
+		match self.days.get(&day.to_string()) {
+			Some(config) if !config.params.is_empty() => toml::Value::Table(config.params.clone())
+				.try_into()
+				.with_context(|| format!("invalid [day.{day}.params] in {CONFIG_FILE}")),
+			_ => Ok(P::default()),
+		}
+	}
+}
+
+pub(crate) fn for_year(year: &Year) -> Result<&'static Config> {
+	let mut configs = CONFIGS.lock().unwrap();
+	if let Some(config) = configs.get(&year.year) {
+		return Ok(config);
+	}
+	let config = Box::leak(Box::new(Config::find(year)?));
+	configs.insert(year.year, config);
+	Ok(config)
+}
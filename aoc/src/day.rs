@@ -0,0 +1,226 @@
+use std::any::Any;
+
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use tracing::{debug_span, info_span};
+
+use crate::{day_name, plugin::Plugin, Solution};
+
+// Parsed input of a day, its concrete type is only known to the day's own part functions
+pub type Parsed = Box<dyn Any>;
+
+// Solves a single part from the parsed input
+pub type Solver = Box<dyn Fn(&Parsed) -> Result<String>>;
+
+/// Name of the implementation of a part given by `Solution::part_one` and `Solution::part_two`.
+pub const DEFAULT_IMPL: &str = "default";
+
+#[derive(Clone)]
+pub struct Day {
+	pub day: u8,
+	pub name: &'static str,
+	functions: Functions,
+}
+
+// Where the parse and part functions of a day come from
+#[derive(Clone)]
+enum Functions {
+	Native {
+		parse: fn(&str) -> Result<Parsed>,
+		part_one: fn(&Parsed) -> Result<String>,
+		part_two: fn(&Parsed) -> Result<String>,
+		// Named alternatives from `Solution::implementations`, as (part, name, solver)
+		alternatives: fn() -> Vec<(u8, &'static str, Solver)>,
+	},
+	Plugin(&'static Plugin),
+}
+
+impl Day {
+	pub const fn new<S>(day: u8, name: &'static str) -> Self
+	where
+		S: Solution,
+		S::Input: 'static,
+	{
+		Self {
+			day,
+			name,
+			functions: Functions::Native {
+				parse: parse::<S>,
+				part_one: part_one::<S>,
+				part_two: part_two::<S>,
+				alternatives: alternatives::<S>,
+			},
+		}
+	}
+
+	/// A day solved by a plugin, which has to stay loaded for as long as the day is used.
+	pub fn from_plugin(plugin: &'static Plugin) -> Result<Self> {
+		let day = plugin.day();
+		let name = day_name(day).ok_or_else(|| anyhow!("there is no day {day}"))?;
+		Ok(Self {
+			day,
+			name,
+			functions: Functions::Plugin(plugin),
+		})
+	}
+
+	pub fn plugin(&self) -> Option<&'static Plugin> {
+		match self.functions {
+			Functions::Native { .. } => None,
+			Functions::Plugin(plugin) => Some(plugin),
+		}
+	}
+
+	pub fn parse(&self, input: &str) -> Result<Parsed> {
+		match self.functions {
+			Functions::Native { parse, .. } => parse(input),
+			Functions::Plugin(plugin) => {
+				let _span = debug_span!("parse").entered();
+				plugin.parse(input)
+			}
+		}
+	}
+
+	pub fn part_one(&self, input: &Parsed) -> Result<String> {
+		self.solve_part(1, input)
+	}
+
+	pub fn part_two(&self, input: &Parsed) -> Result<String> {
+		self.solve_part(2, input)
+	}
+
+	// Solves `part` with its default implementation
+	fn solve_part(&self, part: u8, input: &Parsed) -> Result<String> {
+		match self.functions {
+			Functions::Native { part_one, .. } if part == 1 => part_one(input),
+			Functions::Native { part_two, .. } => part_two(input),
+			Functions::Plugin(plugin) => plugin_part(plugin, part, input),
+		}
+	}
+
+	/// Every implementation of `part` by name, the default one first.
+	pub fn implementations(&self, part: u8) -> Vec<(&'static str, Solver)> {
+		let default: Solver = match self.functions {
+			Functions::Native { part_one, .. } if part == 1 => Box::new(part_one),
+			Functions::Native { part_two, .. } => Box::new(part_two),
+			Functions::Plugin(plugin) => Box::new(move |input| plugin_part(plugin, part, input)),
+		};
+		let mut implementations = vec![(DEFAULT_IMPL, default)];
+		if let Functions::Native { alternatives, .. } = self.functions {
+			for (alternative, name, solve) in alternatives() {
+				if alternative == part {
+					implementations.push((name, solve));
+				}
+			}
+		}
+		implementations
+	}
+
+	pub fn has_implementation(&self, name: &str) -> bool {
+		(1..=2).any(|part| self.implementations(part).iter().any(|(n, _)| *n == name))
+	}
+
+	/// Solvers of both parts using the implementation called `name`, parts that don't have one by
+	/// that name use their default implementation.
+	pub fn solvers(&self, name: &str) -> Result<[Solver; 2]> {
+		if !self.has_implementation(name) {
+			let names = (1..=2)
+				.flat_map(|part| self.implementations(part))
+				.map(|(name, _)| name)
+				.unique()
+				.join(", ");
+			bail!(
+				"day {} has no implementation called {name}, only {names}",
+				self.day
+			);
+		}
+		Ok([1, 2].map(|part| {
+			let mut implementations = self.implementations(part);
+			let i = implementations
+				.iter()
+				.position(|(n, _)| *n == name)
+				.unwrap_or(0);
+			implementations.swap_remove(i).1
+		}))
+	}
+
+	// Parent of the parse and part spans of a single solve
+	pub fn span(&self) -> tracing::Span {
+		info_span!("day", day = self.day)
+	}
+
+	// Name of the input file, which is also how accepted answers refer to it
+	pub fn input_name(&self) -> String {
+		format!("day_{}", self.name)
+	}
+
+	pub fn solve(&self, input: &str) -> Result<[String; 2]> {
+		let _span = self.span().entered();
+		let input = self.parse(input)?;
+		Ok([self.part_one(&input)?, self.part_two(&input)?])
+	}
+}
+
+fn parse<S>(input: &str) -> Result<Parsed>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	let _span = debug_span!("parse").entered();
+	Ok(Box::new(S::parse(input)?))
+}
+
+fn part_one<S>(input: &Parsed) -> Result<String>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	let _span = debug_span!("part", part = 1).entered();
+	Ok(S::part_one(downcast::<S>(input)?)?.to_string())
+}
+
+fn part_two<S>(input: &Parsed) -> Result<String>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	let _span = debug_span!("part", part = 2).entered();
+	Ok(S::part_two(downcast::<S>(input)?)?.to_string())
+}
+
+fn plugin_part(plugin: &Plugin, part: u8, input: &Parsed) -> Result<String> {
+	let _span = debug_span!("part", part).entered();
+	plugin.solve(part, input)
+}
+
+fn alternatives<S>() -> Vec<(u8, &'static str, Solver)>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	S::implementations()
+		.into_iter()
+		.map(|implementation| {
+			let (part, name, solve) = (
+				implementation.part,
+				implementation.name,
+				implementation.solve,
+			);
+			let solver: Solver = Box::new(move |input| {
+				let _span = debug_span!("part", part, implementation = name).entered();
+				solve(downcast::<S>(input)?)
+			});
+			(part, name, solver)
+		})
+		.collect()
+}
+
+fn downcast<S>(input: &Parsed) -> Result<&S::Input>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	input
+		.downcast_ref()
+		.ok_or_else(|| anyhow!("parsed input belongs to another day"))
+}
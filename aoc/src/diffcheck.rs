@@ -0,0 +1,42 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::day::Day;
+
+/// The answers of every implementation of a part on a single input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+	pub part: u8,
+	// Name of the implementation and its answer, or the error it failed with
+	pub answers: Vec<(&'static str, Result<String, String>)>,
+}
+
+impl Comparison {
+	/// Whether every implementation gives the same answer, or fails with the same error.
+	pub fn agrees(&self) -> bool {
+		self.answers.iter().map(|(_, answer)| answer).all_equal()
+	}
+}
+
+/// Whether any part of `day` has an implementation besides the default one.
+pub fn has_alternatives(day: &Day) -> bool {
+	(1..=2).any(|part| day.implementations(part).len() > 1)
+}
+
+/// Parses `input` once and solves each part that has alternatives with all of its implementations.
+pub fn compare(day: &Day, input: &str) -> Result<Vec<Comparison>> {
+	let parsed = day.parse(input)?;
+	Ok(
+		(1..=2)
+			.map(|part| (part, day.implementations(part)))
+			.filter(|(_, implementations)| implementations.len() > 1)
+			.map(|(part, implementations)| Comparison {
+				part,
+				answers: implementations
+					.into_iter()
+					.map(|(name, solve)| (name, solve(&parsed).map_err(|e| format!("{e:#}"))))
+					.collect(),
+			})
+			.collect(),
+	)
+}
@@ -0,0 +1,81 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Result};
+
+use crate::{answers::Answers, Year};
+
+// Examples live in `input/examples` as `day_{name}_{n}`, and their expected answers are recorded in
+// the answers file under that same relative name
+pub fn input_name(day: &str, example: u8) -> String {
+	format!("examples/day_{day}_{example}")
+}
+
+pub fn path(year: &Year, day: &str, example: u8) -> PathBuf {
+	year.input_dir().join(input_name(day, example))
+}
+
+/// Numbers of the non-empty examples of a day, in order.
+pub fn list(year: &Year, day: &str) -> Vec<u8> {
+	(1..)
+		.map_while(|n| Some((n, fs::read_to_string(path(year, day, n)).ok()?)))
+		.filter(|(_, content)| !content.trim().is_empty())
+		.map(|(n, _)| n)
+		.collect()
+}
+
+/// Solves every example of `day` in `year` that has a recorded answer and fails on any mismatch.
+pub fn check(year: &Year, day: u8) -> Result<()> {
+	let day = year.day(day)?;
+	let answers = Answers::load(year.config()?.answers_path())?;
+	let mut checked = 0;
+	let mut mismatches = Vec::new();
+	for example in list(year, day.name) {
+		let name = input_name(day.name, example);
+		let expected = [1, 2].map(|part| answers.get(day.day, part, &name));
+		if expected.iter().all(Option::is_none) {
+			continue;
+		}
+		let parsed = day.parse(&fs::read_to_string(path(year, day.name, example))?)?;
+		for (part, expected) in (1..=2).zip(expected) {
+			let Some(expected) = expected else { continue };
+			// Alternative implementations have to get the same answers
+			for (implementation, solve) in day.implementations(part) {
+				let answer = solve(&parsed)?;
+				if answer != expected {
+					mismatches.push(format!(
+						"example {example} part {part} ({implementation})\nexpected:\n{expected}\ngot:\n{answer}"
+					));
+				}
+			}
+			checked += 1;
+		}
+	}
+	if checked == 0 {
+		bail!(
+			"day {} has no examples with recorded answers, record them with `aoc accept {} --example N`",
+			day.day,
+			day.day
+		);
+	}
+	if !mismatches.is_empty() {
+		bail!("day {}: {}", day.day, mismatches.join("\n"));
+	}
+	Ok(())
+}
+
+/// Generates a `#[test]` per registered day of a year crate that checks its examples against the
+/// recorded answers: `aoc::example_tests!(advent_of_code_2022);`
+#[macro_export]
+macro_rules! example_tests {
+	($year:ident) => {
+		$year::for_each_day!($crate::example_tests);
+	};
+	($krate:tt; $($day:literal => $module:ident::$solution:ident, $name:literal;)*) => {
+		$(
+			#[test]
+			fn $module() {
+				$crate::examples::check(&$krate::YEAR, $day).unwrap();
+			}
+		)*
+	};
+}
@@ -0,0 +1,115 @@
+use std::{
+	fs,
+	io::{self, Read},
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::{config::CONFIG_FILE, day_number, Year};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone)]
+pub enum InputSource {
+	// Reads `day_{name}` from the year's input directory, unless aoc.toml points the day elsewhere
+	Year(&'static Year),
+	// Reads `day_{name}` from a directory of inputs
+	Dir(PathBuf),
+	File(PathBuf),
+	// Example N of a day, saved in input/examples
+	Example(u8, PathBuf),
+	Stdin,
+	Memory(String),
+}
+
+impl InputSource {
+	pub fn load(&self, day: &str) -> Result<String> {
+		match self {
+			Self::Year(year) => {
+				let number = day_number(day).unwrap_or(0);
+				if let Some(path) = day_input(year, number) {
+					return fs::read_to_string(&path).map_err(|e| {
+						anyhow!(
+							"couldn't read the input of day {day} at {}: {e}\ncheck [day.{number}] in {CONFIG_FILE}",
+							path.display()
+						)
+					});
+				}
+				read_day(
+					&year.input_dir(),
+					day,
+					&format!("aoc fetch --year {} {number}", year.year),
+				)
+			}
+			Self::Dir(dir) => read_day(
+				dir,
+				day,
+				&format!("aoc fetch {}", day_number(day).unwrap_or(0)),
+			),
+			Self::File(path) => fs::read_to_string(path).map_err(|e| {
+				anyhow!(
+					"couldn't read the input at {}: {e}\ncheck the path passed to --input",
+					path.display()
+				)
+			}),
+			Self::Example(example, path) => fs::read_to_string(path).map_err(|e| {
+				anyhow!(
+					"couldn't read example {example} of day {day} at {}: {e}\n\
+					save the example there, `aoc read {}` writes the candidates it finds in the puzzle to puzzles/",
+					path.display(),
+					day_number(day).unwrap_or(0)
+				)
+			}),
+			Self::Stdin => {
+				let mut input = String::new();
+				io::stdin()
+					.read_to_string(&mut input)
+					.context("couldn't read the input from stdin")?;
+				Ok(input)
+			}
+			Self::Memory(input) => Ok(input.clone()),
+		}
+	}
+
+	// File the input of `day` is read from, if it comes from one
+	pub fn path(&self, day: &str) -> Option<PathBuf> {
+		match self {
+			Self::Year(year) => Some(
+				day_number(day)
+					.and_then(|number| day_input(year, number))
+					.unwrap_or_else(|| year.input_dir().join(format!("day_{day}"))),
+			),
+			Self::Dir(dir) => Some(dir.join(format!("day_{day}"))),
+			Self::File(path) | Self::Example(_, path) => Some(path.clone()),
+			Self::Stdin | Self::Memory(_) => None,
+		}
+	}
+
+	// Whether every day reads its own input, rather than all of them sharing one
+	pub fn is_per_day(&self) -> bool {
+		matches!(self, Self::Year(_) | Self::Dir(_))
+	}
+}
+
+/// Whether an input has been saved at `path`. `aoc new` leaves an empty file in its place, which
+/// doesn't count.
+pub fn is_saved(path: &Path) -> bool {
+	fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0)
+}
+
+fn read_day(dir: &Path, day: &str, fetch: &str) -> Result<String> {
+	let path = dir.join(format!("day_{day}"));
+	fs::read_to_string(&path).map_err(|e| {
+		anyhow!(
+			"couldn't read the input of day {day} at {}: {e}\n\
+			download it with `{fetch}`, or point --input-dir or {INPUT_DIR_VAR} at the directory containing day_{day}",
+			path.display()
+		)
+	})
+}
+
+// Days can read their input from elsewhere through `[day.N] input` in aoc.toml
+fn day_input(year: &Year, day: u8) -> Option<PathBuf> {
+	year.config().ok()?.day_input(day)
+}
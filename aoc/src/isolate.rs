@@ -0,0 +1,220 @@
+use std::{
+	env,
+	fmt::Display,
+	io::{Read, Write},
+	path::{Path, PathBuf},
+	process::{Child, Command, ExitStatus, Stdio},
+	thread,
+	time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{output::Entry, Year};
+
+// How often a running child is checked for having exited
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+// Printed by the default allocation error handler before it aborts the process
+const ALLOC_FAILED: &str = "memory allocation of";
+
+/// Resource limits of a day run in its own process, `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+	pub timeout: Option<Duration>,
+	// Address space of the child in bytes
+	pub memory: Option<u64>,
+}
+
+impl Limits {
+	pub fn is_limited(&self) -> bool {
+		self.timeout.is_some() || self.memory.is_some()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+	Solved(Vec<Entry>),
+	// Killed once it ran for longer than the timeout
+	Timeout(Duration),
+	OutOfMemory,
+	// The panic message, including where it happened
+	Panic(String),
+	// Any other failure, with the last line the child printed to stderr or how it exited
+	Failed(String),
+}
+
+impl Display for Outcome {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Solved(_) => write!(f, "solved"),
+			Self::Timeout(timeout) => write!(f, "timed out after {timeout:.2?}"),
+			Self::OutOfMemory => write!(f, "ran out of memory"),
+			Self::Panic(message) => write!(f, "panicked: {message}"),
+			Self::Failed(reason) => write!(f, "{reason}"),
+		}
+	}
+}
+
+/// Converts a timeout in seconds, as given on the command line or in aoc.toml.
+pub fn timeout(secs: f64) -> Result<Duration> {
+	match Duration::try_from_secs_f64(secs) {
+		Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+		_ => bail!("timeout must be a positive number of seconds, not {secs}"),
+	}
+}
+
+/// Path of the `day_{name}` binary of `year`, built next to the running executable.
+pub fn binary(year: &Year, name: &str) -> Result<PathBuf> {
+	// Every year builds to the same directory, so a binary there may belong to another year
+	let source = year.root().join(format!("src/bin/day_{name}.rs"));
+	if !source.is_file() {
+		bail!("{} has no day_{name} binary", year.package);
+	}
+	let path = env::current_exe()?.with_file_name(format!("day_{name}{}", env::consts::EXE_SUFFIX));
+	if !path.is_file() {
+		bail!(
+			"{} doesn't exist, build it with `cargo build --workspace --bins`",
+			path.display()
+		);
+	}
+	Ok(path)
+}
+
+/// Runs `binary` with `input` on its stdin and the parts solved by `implementation`, only `part`
+/// if given, killing it when it exceeds `limits`. The binary is expected to take the arguments of
+/// [`crate::run`].
+pub fn run(
+	binary: &Path,
+	input: &str,
+	implementation: &str,
+	part: Option<u8>,
+	limits: &Limits,
+) -> Result<Outcome> {
+	let mut command = Command::new(binary);
+	command.args(["--stdin", "--format", "json", "--impl", implementation]);
+	if let Some(part) = part {
+		command.args(["--part", &part.to_string()]);
+	}
+	command
+		// A backtrace would bury the error or panic message that the outcome is read from
+		.env_remove("RUST_BACKTRACE")
+		.env_remove("RUST_LIB_BACKTRACE")
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped());
+	if let Some(memory) = limits.memory {
+		limit_memory(&mut command, memory)?;
+	}
+	let mut child = command
+		.spawn()
+		.with_context(|| format!("couldn't start {}", binary.display()))?;
+
+	// Pipes are drained on their own threads so a chatty child can't block on a full pipe
+	let mut stdin = child.stdin.take().unwrap();
+	let input = input.to_string();
+	let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+	let stdout = read_all(child.stdout.take().unwrap());
+	let stderr = read_all(child.stderr.take().unwrap());
+
+	let status = wait(&mut child, limits.timeout)?;
+	// The child may exit without reading all of its input
+	let _ = writer.join();
+	let stdout = stdout.join().map_err(|_| anyhow!("couldn't read stdout"))?;
+	let stderr = stderr.join().map_err(|_| anyhow!("couldn't read stderr"))?;
+	Ok(match status {
+		None => Outcome::Timeout(limits.timeout.unwrap_or_default()),
+		Some(status) => classify(status, &stdout, &stderr)?,
+	})
+}
+
+fn read_all(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+	thread::spawn(move || {
+		let mut output = Vec::new();
+		let _ = pipe.read_to_end(&mut output);
+		String::from_utf8_lossy(&output).into_owned()
+	})
+}
+
+// Waits for the child to exit, or kills it and returns `None` once the timeout passes
+fn wait(child: &mut Child, timeout: Option<Duration>) -> Result<Option<ExitStatus>> {
+	let Some(timeout) = timeout else {
+		return Ok(Some(child.wait()?));
+	};
+	let start = Instant::now();
+	loop {
+		if let Some(status) = child.try_wait()? {
+			return Ok(Some(status));
+		}
+		if start.elapsed() >= timeout {
+			child.kill()?;
+			child.wait()?;
+			return Ok(None);
+		}
+		thread::sleep(POLL_INTERVAL);
+	}
+}
+
+fn classify(status: ExitStatus, stdout: &str, stderr: &str) -> Result<Outcome> {
+	if status.success() {
+		let entries = serde_json::from_str(stdout)
+			.with_context(|| format!("couldn't read the answers from the child's output: {stdout}"))?;
+		return Ok(Outcome::Solved(entries));
+	}
+	if stderr.contains(ALLOC_FAILED) {
+		return Ok(Outcome::OutOfMemory);
+	}
+	if let Some(panic) = stderr.lines().find(|l| l.contains("panicked at")) {
+		// Since Rust 1.73 the message is on the line after the location
+		let message = stderr
+			.lines()
+			.skip_while(|l| *l != panic)
+			.nth(1)
+			.filter(|l| !l.starts_with("note:"));
+		return Ok(Outcome::Panic(match message {
+			Some(message) => format!("{message} ({})", location(panic)),
+			None => panic.to_string(),
+		}));
+	}
+	Ok(Outcome::Failed(
+		stderr
+			.lines()
+			.rev()
+			.find(|l| !l.trim().is_empty())
+			// Errors returned from main are prefixed by the standard library
+			.map(|l| l.strip_prefix("Error: ").unwrap_or(l))
+			.map_or_else(|| status.to_string(), str::to_string),
+	))
+}
+
+fn location(panic: &str) -> &str {
+	panic
+		.split_once("panicked at ")
+		.map_or(panic, |(_, at)| at.trim_end_matches(':'))
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) -> Result<()> {
+	use std::os::unix::process::CommandExt;
+
+	let limit = libc::rlimit {
+		rlim_cur: bytes as libc::rlim_t,
+		rlim_max: bytes as libc::rlim_t,
+	};
+	// SAFETY: setrlimit is async-signal-safe, and nothing else runs between fork and exec
+	unsafe {
+		command.pre_exec(move || {
+			if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+				Ok(())
+			} else {
+				Err(std::io::Error::last_os_error())
+			}
+		});
+	}
+	Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_: &mut Command, _: u64) -> Result<()> {
+	bail!("memory limits are only supported on unix")
+}
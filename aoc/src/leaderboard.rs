@@ -0,0 +1,272 @@
+use std::{
+	cmp::Reverse,
+	collections::{BTreeMap, HashMap},
+	fmt::Write,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::client::Client;
+
+// Puzzles unlock at midnight EST
+const UNLOCK_HOUR_UTC: i64 = 5;
+
+/// A private leaderboard as exported by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+	pub event: String,
+	pub owner_id: u64,
+	pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+	pub id: u64,
+	pub name: Option<String>,
+	pub stars: u32,
+	// Score as computed by the site, see Leaderboard::local_scores
+	pub local_score: u64,
+	#[serde(default)]
+	pub global_score: u64,
+	// Stars by day and part
+	#[serde(default)]
+	pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+	pub get_star_ts: i64,
+	// Order in which stars were collected across the whole site, breaks ties between timestamps
+	#[serde(default)]
+	pub star_index: u64,
+}
+
+// The result of a single member on a single day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+	pub member: u64,
+	pub part_one: Option<i64>,
+	pub part_two: Option<i64>,
+	pub points: u64,
+}
+
+impl DayResult {
+	// Seconds between the two stars of the day
+	pub fn delta(&self) -> Option<i64> {
+		Some(self.part_two? - self.part_one?)
+	}
+}
+
+impl Member {
+	pub fn display_name(&self) -> String {
+		match &self.name {
+			Some(name) => name.clone(),
+			None => format!("(anonymous user #{})", self.id),
+		}
+	}
+
+	pub fn star(&self, day: u8, part: u8) -> Option<Star> {
+		self.completion_day_level.get(&day)?.get(&part).copied()
+	}
+}
+
+impl Leaderboard {
+	pub fn parse(json: &str) -> Result<Self> {
+		serde_json::from_str(json).context("invalid leaderboard JSON")
+	}
+
+	pub fn year(&self) -> Result<u16> {
+		self
+			.event
+			.parse()
+			.with_context(|| format!("invalid event '{}'", self.event))
+	}
+
+	/// Unix timestamp at which the puzzle of `day` unlocked.
+	pub fn unlock(&self, day: u8) -> Result<i64> {
+		Ok(days_from_civil(self.year()?.into(), 12, day.into()) * 86400 + UNLOCK_HOUR_UTC * 3600)
+	}
+
+	// Points earned by each member on each part, the first to get a star earns one point per member
+	// of the leaderboard and every later one earns a point less
+	fn points(&self) -> HashMap<(u64, u8, u8), u64> {
+		let members = self.members.len() as u64;
+		let mut points = HashMap::new();
+		for day in 1..=25 {
+			for part in 1..=2 {
+				let mut stars = self
+					.members
+					.values()
+					.filter_map(|m| Some((m.star(day, part)?, m.id)))
+					.collect::<Vec<_>>();
+				stars.sort_by_key(|(star, id)| (star.get_star_ts, star.star_index, *id));
+				for (rank, (_, id)) in stars.into_iter().enumerate() {
+					points.insert((id, day, part), members - rank as u64);
+				}
+			}
+		}
+		points
+	}
+
+	/// Local score of every member, recalculated from the star timestamps.
+	pub fn local_scores(&self) -> HashMap<u64, u64> {
+		let mut scores = self
+			.members
+			.values()
+			.map(|m| (m.id, 0))
+			.collect::<HashMap<_, _>>();
+		for ((id, _, _), points) in self.points() {
+			*scores.entry(id).or_default() += points;
+		}
+		scores
+	}
+
+	/// Members with at least one star of `day`, best first.
+	pub fn day_ranking(&self, day: u8) -> Vec<DayResult> {
+		let points = self.points();
+		let mut results = self
+			.members
+			.values()
+			.filter_map(|m| {
+				let part_one = m.star(day, 1).map(|s| s.get_star_ts);
+				let part_two = m.star(day, 2).map(|s| s.get_star_ts);
+				part_one.or(part_two)?;
+				Some(DayResult {
+					member: m.id,
+					part_one,
+					part_two,
+					points: (1..=2)
+						.filter_map(|part| points.get(&(m.id, day, part)))
+						.sum(),
+				})
+			})
+			.collect::<Vec<_>>();
+		results.sort_by_key(|r| {
+			(
+				Reverse(r.points),
+				r.part_two.unwrap_or(i64::MAX),
+				r.part_one.unwrap_or(i64::MAX),
+				r.member,
+			)
+		});
+		results
+	}
+
+	fn member(&self, id: u64) -> Option<&Member> {
+		self.members.values().find(|m| m.id == id)
+	}
+
+	/// Members by recalculated local score, with a star per solved day like on the website.
+	pub fn standings(&self) -> String {
+		let scores = self.local_scores();
+		let mut members = self.members.values().collect::<Vec<_>>();
+		members.sort_by_key(|m| (Reverse(scores[&m.id]), Reverse(m.stars), m.id));
+		let width = name_width(members.iter().copied());
+
+		let mut out = String::new();
+		writeln!(
+			out,
+			"Rank | {:<width$} | Score | Site  | Stars | {}",
+			"Member",
+			(1..=25).map(|d| (d % 10).to_string()).collect::<String>()
+		)
+		.unwrap();
+		writeln!(
+			out,
+			"-----+-{}-+-------+-------+-------+-{}",
+			"-".repeat(width),
+			"-".repeat(25)
+		)
+		.unwrap();
+		for (rank, member) in members.into_iter().enumerate() {
+			let days = (1..=25)
+				.map(|day| match (member.star(day, 1), member.star(day, 2)) {
+					(Some(_), Some(_)) => '*',
+					(Some(_), None) | (None, Some(_)) => '.',
+					(None, None) => ' ',
+				})
+				.collect::<String>();
+			let row = format!(
+				"{:>4} | {:<width$} | {:>5} | {:>5} | {:>5} | {days}",
+				rank + 1,
+				member.display_name(),
+				scores[&member.id],
+				member.local_score,
+				member.stars,
+			);
+			writeln!(out, "{}", row.trim_end()).unwrap();
+		}
+		out
+	}
+
+	/// Star times of `day` relative to its unlock, and the points each member earned with them.
+	pub fn day_table(&self, day: u8) -> Result<String> {
+		let unlock = self.unlock(day)?;
+		let ranking = self.day_ranking(day);
+		let width = name_width(ranking.iter().filter_map(|r| self.member(r.member)));
+		let time = |ts: Option<i64>| ts.map_or_else(|| "-".to_string(), |ts| elapsed(ts - unlock));
+
+		let mut out = String::new();
+		writeln!(
+			out,
+			"Rank | {:<width$} | {:>10} | {:>10} | {:>10} | Points",
+			"Member", "Part 1", "Part 2", "Delta"
+		)
+		.unwrap();
+		writeln!(
+			out,
+			"-----+-{}-+-{}-+-------",
+			"-".repeat(width),
+			vec!["-".repeat(10); 3].join("-+-")
+		)
+		.unwrap();
+		for (rank, result) in ranking.iter().enumerate() {
+			writeln!(
+				out,
+				"{:>4} | {:<width$} | {:>10} | {:>10} | {:>10} | {:>6}",
+				rank + 1,
+				self
+					.member(result.member)
+					.map(Member::display_name)
+					.unwrap_or_default(),
+				time(result.part_one),
+				time(result.part_two),
+				result.delta().map_or_else(|| "-".to_string(), elapsed),
+				result.points
+			)
+			.unwrap();
+		}
+		Ok(out)
+	}
+}
+
+pub fn fetch(client: &Client, id: u64) -> Result<Leaderboard> {
+	Leaderboard::parse(&client.get(&format!(
+		"/{}/leaderboard/private/view/{id}.json",
+		client.year
+	))?)
+}
+
+fn name_width<'a>(members: impl Iterator<Item = &'a Member>) -> usize {
+	members
+		.map(|m| m.display_name().chars().count())
+		.max()
+		.unwrap_or(0)
+		.max("Member".len())
+}
+
+// Formats a number of seconds as h:mm:ss, hours aren't wrapped into days
+fn elapsed(secs: i64) -> String {
+	format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+// Days since the Unix epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
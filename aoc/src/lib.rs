@@ -0,0 +1,211 @@
+use std::{any::Any, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use day::Day;
+use input::InputSource;
+use output::Format;
+pub use year::Year;
+
+pub mod allocs;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod day;
+pub mod diffcheck;
+pub mod examples;
+pub mod input;
+pub mod isolate;
+pub mod leaderboard;
+pub mod output;
+pub mod parallel;
+pub mod plugin;
+pub mod progress;
+pub mod puzzle;
+pub mod scaffold;
+pub mod shrink;
+pub mod submit;
+pub mod trace;
+#[cfg(target_os = "linux")]
+pub mod watch;
+mod year;
+
+const DAY_NAMES: [&str; 25] = [
+	"one",
+	"two",
+	"three",
+	"four",
+	"five",
+	"six",
+	"seven",
+	"eight",
+	"nine",
+	"ten",
+	"eleven",
+	"twelve",
+	"thirteen",
+	"fourteen",
+	"fifteen",
+	"sixteen",
+	"seventeen",
+	"eighteen",
+	"nineteen",
+	"twenty",
+	"twenty_one",
+	"twenty_two",
+	"twenty_three",
+	"twenty_four",
+	"twenty_five",
+];
+
+// Spelled out name of a day, as used by input files and binaries
+pub fn day_name(day: u8) -> Option<&'static str> {
+	DAY_NAMES.get(usize::from(day).checked_sub(1)?).copied()
+}
+
+pub fn day_number(name: &str) -> Option<u8> {
+	DAY_NAMES
+		.iter()
+		.position(|n| *n == name)
+		.map(|i| i as u8 + 1)
+}
+
+/// Message of a caught panic, which is usually a string.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		message.to_string()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.clone()
+	} else {
+		String::from("unknown panic")
+	}
+}
+
+/// A day's puzzle, split into parsing its input and solving both parts from the parsed input.
+pub trait Solution {
+	type Input;
+	type PartOne: Display;
+	type PartTwo: Display;
+
+	fn parse(input: &str) -> Result<Self::Input>;
+	fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+	fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+
+	/// Other implementations of the parts, selected by name with `--impl` and compared against
+	/// `part_one` and `part_two` by `aoc diffcheck`.
+	fn implementations() -> Vec<Implementation<Self::Input>> {
+		Vec::new()
+	}
+}
+
+/// A named alternative way to solve a part, like a naive version kept next to an optimised one.
+pub struct Implementation<I> {
+	pub name: &'static str,
+	pub part: u8,
+	pub solve: fn(&I) -> Result<String>,
+}
+
+pub fn solve<S: Solution>(input: &str) -> Result<[String; 2]> {
+	let input = S::parse(input)?;
+	Ok([
+		S::part_one(&input)?.to_string(),
+		S::part_two(&input)?.to_string(),
+	])
+}
+
+#[derive(Parser)]
+struct RunArgs {
+	/// How to print the answers
+	#[arg(long, value_enum, default_value_t)]
+	format: Format,
+	/// Read the input from stdin instead of the input directory
+	#[arg(long)]
+	stdin: bool,
+	/// Solve the parts with the implementation of this name, where they have one
+	#[arg(long = "impl", value_name = "NAME", default_value = day::DEFAULT_IMPL)]
+	implementation: String,
+	/// Only solve this part
+	#[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: Option<u8>,
+}
+
+/// Loads the input of `day` in `year`, solves both parts, or the one passed as `--part`, and prints their answers in the `--format`
+/// passed to the binary.
+pub fn run<S>(year: &'static Year, day: &str) -> Result<()>
+where
+	S: Solution,
+	S::Input: 'static,
+{
+	let args = RunArgs::parse();
+	// Surface mistakes in aoc.toml instead of quietly falling back to the defaults
+	year.config()?;
+	let number = day_number(day).ok_or_else(|| anyhow!("unknown day {day}"))?;
+	let day = Day::new::<S>(number, DAY_NAMES[number as usize - 1]);
+	let source = if args.stdin {
+		InputSource::Stdin
+	} else {
+		InputSource::Year(year)
+	};
+	let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+	let input = source.load(day.name)?;
+	let entries = output::solve_parts(&day, &input, &args.implementation, &parts)?;
+	match args.format {
+		Format::Text => {
+			for entry in &entries {
+				print_answer(["one", "two"][entry.part as usize - 1], &entry.answer);
+			}
+		}
+		Format::Json => println!("{}", output::json(&entries)?),
+		Format::Csv => print!("{}", output::csv(&entries)),
+	}
+	Ok(())
+}
+
+fn print_answer(part: &str, answer: &str) {
+	if answer.contains('\n') {
+		println!("Part {part}: \u{2193}\n{answer}");
+	} else {
+		println!("Part {part}: {answer}");
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+	rows: Vec<Vec<T>>,
+	cols: Vec<Vec<T>>,
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T>
+where
+	T: Sized + Clone + Copy,
+{
+	fn from(rows: Vec<Vec<T>>) -> Self {
+		let cols = (0..rows[0].len())
+			.map(|i| rows.iter().map(|row| row[i]).collect())
+			.collect();
+		Self { rows, cols }
+	}
+}
+
+impl<T> Grid<T> {
+	pub fn rows(&self) -> &[Vec<T>] {
+		self.rows.as_ref()
+	}
+
+	pub fn row(&self, idx: usize) -> Result<&[T]> {
+		if idx < self.rows.len() {
+			Ok(&self.rows[idx])
+		} else {
+			Err(anyhow!("Invalid row index: {}", idx))
+		}
+	}
+
+	pub fn col(&self, idx: usize) -> Result<&[T]> {
+		if idx < self.cols.len() {
+			Ok(&self.cols[idx])
+		} else {
+			Err(anyhow!("Invalid column index: {}", idx))
+		}
+	}
+}
@@ -0,0 +1,138 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	allocs::{self, Stats},
+	bench::{as_nanos, from_nanos},
+	day::{Day, Solver, DEFAULT_IMPL},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+	/// Human readable answers
+	#[default]
+	Text,
+	/// An array of entries
+	Json,
+	/// A header row followed by a row per entry
+	Csv,
+}
+
+// The answer of a single part, with how long it took to get there. The parse time is shared by both
+// parts of a day, since the input is only parsed once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+	pub day: u8,
+	pub part: u8,
+	pub answer: String,
+	#[serde(
+		rename = "parse_ns",
+		serialize_with = "as_nanos",
+		deserialize_with = "from_nanos"
+	)]
+	pub parse: Duration,
+	#[serde(
+		rename = "solve_ns",
+		serialize_with = "as_nanos",
+		deserialize_with = "from_nanos"
+	)]
+	pub solve: Duration,
+	// Only counted with `--alloc-stats`, see crate::allocs
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub parse_alloc: Option<Stats>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub solve_alloc: Option<Stats>,
+}
+
+/// Solves both parts of `day` like [`Day::solve`], timing parsing and each part and counting their
+/// allocations if [`allocs::enable`] was called.
+pub fn solve_timed(day: &Day, input: &str) -> Result<[Entry; 2]> {
+	solve_timed_with(day, input, DEFAULT_IMPL)
+}
+
+/// Like [`solve_timed`], using the implementation of each part called `implementation` if it has
+/// one.
+pub fn solve_timed_with(day: &Day, input: &str, implementation: &str) -> Result<[Entry; 2]> {
+	let entries = solve_parts(day, input, implementation, &[1, 2])?;
+	Ok(
+		entries
+			.try_into()
+			.unwrap_or_else(|_| unreachable!("both parts are solved")),
+	)
+}
+
+/// Like [`solve_timed_with`], only solving `parts`, so a part that wasn't asked for can't fail. The
+/// first part that fails is returned as the error, before any later part is solved.
+pub fn solve_parts(
+	day: &Day,
+	input: &str,
+	implementation: &str,
+	parts: &[u8],
+) -> Result<Vec<Entry>> {
+	let solvers = day.solvers(implementation)?;
+	let _span = day.span().entered();
+	let start = Instant::now();
+	let (parsed, parse_alloc) = allocs::measure(|| day.parse(input));
+	let parse = start.elapsed();
+	let parsed = parsed?;
+	let entry = |part, solve: Solver| -> Result<Entry> {
+		let start = Instant::now();
+		let (answer, solve_alloc) = allocs::measure(|| solve(&parsed));
+		let solve = start.elapsed();
+		Ok(Entry {
+			day: day.day,
+			part,
+			answer: answer?,
+			parse,
+			solve,
+			parse_alloc,
+			solve_alloc,
+		})
+	};
+	solvers
+		.into_iter()
+		.zip(1..)
+		.filter(|(_, part)| parts.contains(part))
+		.map(|(solve, part)| entry(part, solve))
+		.collect()
+}
+
+pub fn json(entries: &[Entry]) -> Result<String> {
+	Ok(serde_json::to_string_pretty(entries)?)
+}
+
+// Answers are always quoted, so commas, quotes and the newlines of multi-line answers survive as is.
+// Allocation columns are only added when allocations were counted.
+pub fn csv(entries: &[Entry]) -> String {
+	let allocs = entries.iter().any(|e| e.parse_alloc.is_some());
+	let mut csv = String::from("day,part,answer,parse_ns,solve_ns");
+	if allocs {
+		csv.push_str(",parse_allocations,parse_bytes,parse_peak_bytes");
+		csv.push_str(",solve_allocations,solve_bytes,solve_peak_bytes");
+	}
+	csv.push('\n');
+	for entry in entries {
+		csv.push_str(&format!(
+			"{},{},\"{}\",{},{}",
+			entry.day,
+			entry.part,
+			entry.answer.replace('"', "\"\""),
+			entry.parse.as_nanos(),
+			entry.solve.as_nanos()
+		));
+		if allocs {
+			for stats in [entry.parse_alloc, entry.solve_alloc] {
+				let stats = stats.unwrap_or_default();
+				csv.push_str(&format!(
+					",{},{},{}",
+					stats.allocations, stats.bytes, stats.peak_bytes
+				));
+			}
+		}
+		csv.push('\n');
+	}
+	csv
+}
@@ -0,0 +1,39 @@
+use std::{
+	num::NonZeroUsize,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Mutex,
+	},
+	thread,
+};
+
+/// Applies `f` to every item on up to `jobs` threads, returning the results in the order of
+/// `items` whatever order they finished in. Items are handed out one at a time, so a slow one
+/// doesn't hold up the ones queued behind it.
+pub fn map<T, R, F>(items: &[T], jobs: NonZeroUsize, f: F) -> Vec<R>
+where
+	T: Sync,
+	R: Send,
+	F: Fn(&T) -> R + Sync,
+{
+	let next = AtomicUsize::new(0);
+	let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+	thread::scope(|scope| {
+		for _ in 0..jobs.get().min(items.len()) {
+			scope.spawn(|| loop {
+				let i = next.fetch_add(1, Ordering::Relaxed);
+				let Some(item) = items.get(i) else {
+					break;
+				};
+				let result = f(item);
+				results.lock().unwrap()[i] = Some(result);
+			});
+		}
+	});
+	results
+		.into_inner()
+		.unwrap()
+		.into_iter()
+		.map(|r| r.expect("every item is mapped before the scope ends"))
+		.collect()
+}
@@ -0,0 +1,376 @@
+use std::{
+	env,
+	ffi::{c_char, c_void, CStr, CString},
+	fmt, fs,
+	panic::{self, AssertUnwindSafe},
+	path::{Path, PathBuf},
+	ptr, slice, str,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use libloading::Library;
+
+use crate::{
+	day::{Day, Parsed},
+	panic_message, Solution, Year,
+};
+
+/// Version of [`Descriptor`], bumped whenever its layout or the contract of its functions changes.
+pub const ABI_VERSION: u32 = 1;
+
+/// Function every plugin exports, taking no arguments and returning a pointer to its [`Descriptor`].
+pub const DESCRIPTOR_SYMBOL: &str = "aoc_plugin_descriptor";
+
+/// Environment variable holding the directory plugins are loaded from, like `--plugins`.
+pub const PLUGIN_DIR_VAR: &str = "AOC_PLUGIN_DIR";
+
+// Parses `len` bytes of UTF-8 input, returning the parsed input or null with `*error` set
+pub type ParseFn =
+	unsafe extern "C" fn(input: *const u8, len: usize, error: *mut *mut c_char) -> *mut c_void;
+
+// Solves a part from the parsed input, returning its answer or null with `*error` set
+pub type PartFn =
+	unsafe extern "C" fn(parsed: *const c_void, error: *mut *mut c_char) -> *mut c_char;
+
+/// The C ABI between a plugin and aoc, describing the single day a plugin solves. Strings from the
+/// plugin are NUL-terminated UTF-8 that aoc hands back to `free_string` once read, the parsed input
+/// goes back to `free_parsed`. Errors may be left null when there's no message.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Descriptor {
+	// Always the first field, so it can be checked before trusting the rest of the layout
+	pub abi_version: u32,
+	pub year: u16,
+	pub day: u8,
+	pub parse: ParseFn,
+	pub part_one: PartFn,
+	pub part_two: PartFn,
+	pub free_parsed: unsafe extern "C" fn(parsed: *mut c_void),
+	pub free_string: unsafe extern "C" fn(string: *mut c_char),
+}
+
+impl Descriptor {
+	/// The descriptor of a plugin solving `day` of `year` with `S`, as exported by
+	/// [`export_plugin!`](crate::export_plugin).
+	pub const fn new<S>(year: u16, day: u8) -> Self
+	where
+		S: Solution,
+		S::Input: 'static,
+	{
+		Self {
+			abi_version: ABI_VERSION,
+			year,
+			day,
+			parse: export_parse::<S>,
+			part_one: export_part::<S, 1>,
+			part_two: export_part::<S, 2>,
+			free_parsed: export_free_parsed::<S>,
+			free_string: export_free_string,
+		}
+	}
+}
+
+/// Exports the [`Descriptor`] of a `cdylib` crate solving a single day with a [`Solution`]:
+/// `aoc::export_plugin!(2022, 15, DayFifteen);`
+#[macro_export]
+macro_rules! export_plugin {
+	($year:literal, $day:literal, $solution:ty) => {
+		#[no_mangle]
+		pub extern "C" fn aoc_plugin_descriptor() -> *const $crate::plugin::Descriptor {
+			static DESCRIPTOR: $crate::plugin::Descriptor =
+				$crate::plugin::Descriptor::new::<$solution>($year, $day);
+			&DESCRIPTOR
+		}
+	};
+}
+
+// Panics can't unwind into the caller across the C ABI, so they become errors
+fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	panic::catch_unwind(AssertUnwindSafe(f))
+		.unwrap_or_else(|panic| Err(anyhow!("panicked: {}", panic_message(&*panic))))
+}
+
+// Hands `result` over to the caller as an owned string, or null with `*error` set
+unsafe fn export_string(result: Result<String>, error: *mut *mut c_char) -> *mut c_char {
+	match result.and_then(|string| Ok(CString::new(string)?)) {
+		Ok(string) => string.into_raw(),
+		Err(e) => {
+			export_error(e, error);
+			ptr::null_mut()
+		}
+	}
+}
+
+unsafe fn export_error(e: anyhow::Error, error: *mut *mut c_char) {
+	if !error.is_null() {
+		let message = format!("{e:#}").replace('\0', " ");
+		*error = CString::new(message).unwrap_or_default().into_raw();
+	}
+}
+
+unsafe extern "C" fn export_parse<S>(
+	input: *const u8,
+	len: usize,
+	error: *mut *mut c_char,
+) -> *mut c_void
+where
+	S: Solution,
+{
+	let parsed = catch(|| {
+		let input = str::from_utf8(slice::from_raw_parts(input, len)).context("input isn't UTF-8")?;
+		S::parse(input)
+	});
+	match parsed {
+		Ok(parsed) => Box::into_raw(Box::new(parsed)).cast(),
+		Err(e) => {
+			export_error(e, error);
+			ptr::null_mut()
+		}
+	}
+}
+
+unsafe extern "C" fn export_part<S, const PART: u8>(
+	parsed: *const c_void,
+	error: *mut *mut c_char,
+) -> *mut c_char
+where
+	S: Solution,
+{
+	let input = &*parsed.cast::<S::Input>();
+	let answer = catch(|| match PART {
+		1 => Ok(S::part_one(input)?.to_string()),
+		_ => Ok(S::part_two(input)?.to_string()),
+	});
+	export_string(answer, error)
+}
+
+unsafe extern "C" fn export_free_parsed<S>(parsed: *mut c_void)
+where
+	S: Solution,
+{
+	if !parsed.is_null() {
+		drop(Box::from_raw(parsed.cast::<S::Input>()));
+	}
+}
+
+unsafe extern "C" fn export_free_string(string: *mut c_char) {
+	if !string.is_null() {
+		drop(CString::from_raw(string));
+	}
+}
+
+/// A loaded plugin. Days refer to its functions for as long as they exist, so the library is never
+/// unloaded once its days are registered.
+pub struct Plugin {
+	path: PathBuf,
+	descriptor: Descriptor,
+	// Keeps the functions of the descriptor loaded
+	_library: Library,
+}
+
+impl fmt::Debug for Plugin {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Plugin")
+			.field("path", &self.path)
+			.field("year", &self.descriptor.year)
+			.field("day", &self.descriptor.day)
+			.finish_non_exhaustive()
+	}
+}
+
+// Parsed input owned by a plugin, handed back to it when dropped
+struct PluginInput {
+	plugin: &'static Plugin,
+	parsed: *mut c_void,
+}
+
+impl Drop for PluginInput {
+	fn drop(&mut self) {
+		// SAFETY: the pointer came from the plugin's parse function and is only freed once
+		unsafe { (self.plugin.descriptor.free_parsed)(self.parsed) }
+	}
+}
+
+/// Checks that aoc can use `descriptor`, starting with its ABI version.
+pub fn check(descriptor: &Descriptor) -> Result<()> {
+	check_version(descriptor.abi_version)?;
+	if descriptor.year < 2015 {
+		bail!("{} isn't a year of Advent of Code", descriptor.year);
+	}
+	if !(1..=25).contains(&descriptor.day) {
+		bail!("there is no day {} in Advent of Code", descriptor.day);
+	}
+	Ok(())
+}
+
+fn check_version(version: u32) -> Result<()> {
+	if version != ABI_VERSION {
+		bail!(
+			"it was built for plugin ABI version {version}, but this aoc only loads version \
+			 {ABI_VERSION}, rebuild it against the aoc crate of this workspace"
+		);
+	}
+	Ok(())
+}
+
+/// Loads the plugin at `path` and checks its descriptor.
+pub fn load(path: &Path) -> Result<Plugin> {
+	let context = || format!("couldn't load plugin {}", path.display());
+	// SAFETY: loading a library runs its initializers, plugins are trusted like any other code
+	let library = unsafe { Library::new(path) }.with_context(context)?;
+	// SAFETY: the symbol has the signature plugins are documented to export, and the version is read
+	// on its own before the rest of the descriptor
+	let descriptor = unsafe {
+		let export = library
+			.get::<unsafe extern "C" fn() -> *const Descriptor>(DESCRIPTOR_SYMBOL.as_bytes())
+			.map_err(|_| anyhow!("it doesn't export {DESCRIPTOR_SYMBOL}, see aoc::export_plugin!"))
+			.with_context(context)?;
+		let descriptor = export();
+		if descriptor.is_null() {
+			return Err(anyhow!("its descriptor is null")).with_context(context);
+		}
+		check_version(descriptor.cast::<u32>().read()).with_context(context)?;
+		descriptor.read()
+	};
+	check(&descriptor).with_context(context)?;
+	Ok(Plugin {
+		path: path.to_path_buf(),
+		descriptor,
+		_library: library,
+	})
+}
+
+/// Loads every shared library in `dir`, in order of their file names.
+pub fn load_dir(dir: &Path) -> Result<Vec<Plugin>> {
+	let mut paths = fs::read_dir(dir)
+		.with_context(|| format!("couldn't read the plugin directory {}", dir.display()))?
+		.map(|entry| Ok(entry?.path()))
+		.collect::<Result<Vec<_>>>()?;
+	paths.retain(|path| {
+		path
+			.extension()
+			.is_some_and(|e| e == env::consts::DLL_EXTENSION)
+	});
+	paths.sort();
+	paths.iter().map(|path| load(path)).collect()
+}
+
+/// Adds the days of `plugins` to the years they belong to, keeping years without plugins as they
+/// are. A plugin can't replace a built-in day, nor solve a day another plugin already does.
+pub fn register(years: &[&'static Year], plugins: Vec<Plugin>) -> Result<Vec<&'static Year>> {
+	for plugin in &plugins {
+		if !years.iter().any(|y| y.year == plugin.year()) {
+			bail!(
+				"plugin {} solves day {} of {}, but there are no solutions for {} to add it to",
+				plugin.path.display(),
+				plugin.day(),
+				plugin.year(),
+				plugin.year()
+			);
+		}
+	}
+	let plugins = plugins
+		.into_iter()
+		.map(|plugin| &*Box::leak(Box::new(plugin)))
+		.collect::<Vec<_>>();
+	years
+		.iter()
+		.map(|&year| {
+			let mut days = year.days.to_vec();
+			for &plugin in plugins.iter().filter(|p| p.year() == year.year) {
+				if let Some(existing) = days.iter().find(|d| d.day == plugin.day()) {
+					bail!(
+						"plugin {} solves day {} of {}, which {}",
+						plugin.path.display(),
+						plugin.day(),
+						year.year,
+						match existing.plugin() {
+							Some(other) => format!("plugin {} already solves", other.path.display()),
+							None => String::from("is already built in"),
+						}
+					);
+				}
+				days.push(Day::from_plugin(plugin)?);
+			}
+			if days.len() == year.days.len() {
+				return Ok(year);
+			}
+			days.sort_by_key(|d| d.day);
+			let year: &'static Year = Box::leak(Box::new(Year {
+				year: year.year,
+				package: year.package,
+				root: year.root,
+				days: Box::leak(days.into_boxed_slice()),
+			}));
+			Ok(year)
+		})
+		.collect()
+}
+
+impl Plugin {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn year(&self) -> u16 {
+		self.descriptor.year
+	}
+
+	pub fn day(&self) -> u8 {
+		self.descriptor.day
+	}
+
+	pub(crate) fn parse(&'static self, input: &str) -> Result<Parsed> {
+		let mut error = ptr::null_mut();
+		// SAFETY: the input outlives the call, and the plugin only writes a string to `error`
+		let parsed = unsafe { (self.descriptor.parse)(input.as_ptr(), input.len(), &mut error) };
+		if parsed.is_null() {
+			return Err(self.error(error));
+		}
+		Ok(Box::new(PluginInput {
+			plugin: self,
+			parsed,
+		}))
+	}
+
+	pub(crate) fn solve(&self, part: u8, input: &Parsed) -> Result<String> {
+		let input = input
+			.downcast_ref::<PluginInput>()
+			.filter(|input| ptr::eq(input.plugin, self))
+			.ok_or_else(|| anyhow!("parsed input belongs to another day"))?;
+		let solve = match part {
+			1 => self.descriptor.part_one,
+			_ => self.descriptor.part_two,
+		};
+		let mut error = ptr::null_mut();
+		// SAFETY: the parsed input came from this plugin and hasn't been freed yet
+		let answer = unsafe { solve(input.parsed, &mut error) };
+		if answer.is_null() {
+			return Err(self.error(error));
+		}
+		self
+			.take_string(answer)
+			.with_context(|| format!("plugin {} gave an invalid answer", self.path.display()))
+	}
+
+	// Copies a string from the plugin and frees it
+	fn take_string(&self, string: *mut c_char) -> Result<String> {
+		// SAFETY: the plugin returned a NUL-terminated string that nothing else frees
+		let copied = unsafe { CStr::from_ptr(string) }
+			.to_str()
+			.map(str::to_string)
+			.context("it isn't UTF-8");
+		unsafe { (self.descriptor.free_string)(string) };
+		copied
+	}
+
+	fn error(&self, error: *mut c_char) -> anyhow::Error {
+		if error.is_null() {
+			return anyhow!("plugin {} failed without saying why", self.path.display());
+		}
+		match self.take_string(error) {
+			Ok(message) => anyhow!(message),
+			Err(e) => e.context(format!("plugin {} failed", self.path.display())),
+		}
+	}
+}
@@ -0,0 +1,155 @@
+use std::{
+	fs,
+	io::ErrorKind,
+	path::Path,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	input::{self, InputSource},
+	output::Entry,
+	Year,
+};
+
+// The latest run of a part on the real input, with the answer it was expected to give at the time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartProgress {
+	pub day: u8,
+	pub part: u8,
+	pub answer: String,
+	pub expected: Option<String>,
+	pub parse_ns: u64,
+	pub solve_ns: u64,
+	// Seconds since the Unix epoch
+	pub updated: u64,
+}
+
+impl PartProgress {
+	pub fn is_verified(&self) -> bool {
+		self.expected.as_deref() == Some(self.answer.as_str())
+	}
+}
+
+/// Local record of how far along a year is, kept up to date by `aoc run` and `aoc verify`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Progress {
+	#[serde(default, rename = "part")]
+	parts: Vec<PartProgress>,
+}
+
+impl Progress {
+	pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		match fs::read_to_string(path) {
+			Ok(content) => toml::from_str(&content)
+				.with_context(|| format!("invalid progress file {}", path.display())),
+			Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+			Err(e) => Err(e).with_context(|| format!("couldn't read {}", path.display())),
+		}
+	}
+
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		fs::write(path, toml::to_string_pretty(self)?)
+			.with_context(|| format!("couldn't write {}", path.display()))
+	}
+
+	pub fn get(&self, day: u8, part: u8) -> Option<&PartProgress> {
+		self.parts.iter().find(|p| p.day == day && p.part == part)
+	}
+
+	pub fn record(&mut self, entry: &Entry, expected: Option<&str>) {
+		let progress = PartProgress {
+			day: entry.day,
+			part: entry.part,
+			answer: entry.answer.clone(),
+			expected: expected.map(String::from),
+			parse_ns: entry.parse.as_nanos() as u64,
+			solve_ns: entry.solve.as_nanos() as u64,
+			updated: SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.map_or(0, |d| d.as_secs()),
+		};
+		match self
+			.parts
+			.iter_mut()
+			.find(|p| p.day == entry.day && p.part == entry.part)
+		{
+			Some(existing) => *existing = progress,
+			None => {
+				self.parts.push(progress);
+				self.parts.sort_by_key(|p| (p.day, p.part));
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayStatus {
+	pub day: u8,
+	pub solved: bool,
+	pub input: bool,
+	pub parts: Vec<PartProgress>,
+}
+
+impl DayStatus {
+	// Parts whose latest answer matched the expected one
+	pub fn stars(&self) -> usize {
+		self.parts.iter().filter(|p| p.is_verified()).count()
+	}
+}
+
+/// Status of every day of the year, whether it exists yet or not.
+pub fn status(year: &'static Year, progress: &Progress) -> Vec<DayStatus> {
+	let source = InputSource::Year(year);
+	(1..=25)
+		.map(|day| {
+			let input = crate::day_name(day)
+				.and_then(|name| source.path(name))
+				.is_some_and(|path| input::is_saved(&path));
+			DayStatus {
+				day,
+				solved: year.find(day).is_some(),
+				input,
+				parts: (1..=2)
+					.filter_map(|part| progress.get(day, part).cloned())
+					.collect(),
+			}
+		})
+		.collect()
+}
+
+/// Draws the days as an advent calendar, one week per row starting on Monday.
+pub fn calendar(year: u16, days: &[DayStatus]) -> String {
+	let mut calendar = String::from(" Mo     Tu     We     Th     Fr     Sa     Su\n");
+	let offset = weekday(year, 12, 1);
+	calendar.push_str(&"       ".repeat(offset));
+	for (i, status) in days.iter().enumerate() {
+		let stars = match (status.solved, status.stars()) {
+			(false, _) => "  ",
+			(true, 0) => "..",
+			(true, 1) => "* ",
+			(true, _) => "**",
+		};
+		let cell = format!("{:>2} {stars}", status.day);
+		calendar.push_str(&cell);
+		if (offset + i + 1).is_multiple_of(7) {
+			calendar.push('\n');
+		} else {
+			calendar.push_str("   ");
+		}
+	}
+	calendar.lines().map(str::trim_end).join("\n") + "\n"
+}
+
+// Day of the week of a date, 0 being Monday (Sakamoto's method)
+fn weekday(year: u16, month: usize, day: u16) -> usize {
+	const OFFSETS: [u16; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+	let year = if month < 3 { year - 1 } else { year };
+	let sunday_based = (year + year / 4 - year / 100 + year / 400 + OFFSETS[month - 1] + day) % 7;
+	(usize::from(sunday_based) + 6) % 7
+}
@@ -0,0 +1,161 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+use crate::{client::Client, Year};
+
+lazy_static! {
+	static ref ARTICLE_RE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+	static ref TOKEN_RE: Regex = Regex::new(r"(?s)<(/?)([a-zA-Z0-9]+)([^>]*)>|([^<]+)").unwrap();
+	static ref HREF_RE: Regex = Regex::new(r#"href="([^"]*)""#).unwrap();
+	static ref PRE_RE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+	static ref CODE_EM_RE: Regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+	static ref EM_CODE_RE: Regex = Regex::new(r"(?s)<em><code>(.*?)</code></em>").unwrap();
+	static ref TAG_RE: Regex = Regex::new(r"<[^>]+>").unwrap();
+	static ref SPACE_RE: Regex = Regex::new(r"\s+").unwrap();
+}
+
+// A puzzle description as shown on its day page, one article per unlocked part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+	pub markdown: String,
+	// Contents of every `<pre><code>` block, in page order
+	pub examples: Vec<String>,
+	// Emphasized code of each part, the last one usually being the example's answer
+	pub answers: Vec<Vec<String>>,
+}
+
+impl Puzzle {
+	pub fn parse(html: &str) -> Result<Self> {
+		let articles = ARTICLE_RE
+			.captures_iter(html)
+			.map(|c| c.get(1).unwrap().as_str())
+			.collect::<Vec<_>>();
+		if articles.is_empty() {
+			bail!("no puzzle description found, is the day unlocked yet?");
+		}
+		Ok(Self {
+			markdown: articles
+				.iter()
+				.map(|article| to_markdown(article))
+				.collect::<Vec<_>>()
+				.join("\n"),
+			examples: articles
+				.iter()
+				.flat_map(|article| PRE_RE.captures_iter(article))
+				.map(|c| decode(&TAG_RE.replace_all(&c[1], "")))
+				.collect(),
+			answers: articles.iter().map(|article| emphasized(article)).collect(),
+		})
+	}
+}
+
+pub fn fetch(client: &Client, day: u8) -> Result<Puzzle> {
+	Puzzle::parse(&client.get(&format!("/{}/day/{day}", client.year))?)
+}
+
+/// Directory the puzzle descriptions and their candidate examples of `year` are written to.
+pub fn dir(year: &Year) -> PathBuf {
+	year.root().join("puzzles")
+}
+
+/// Writes the description of `day` to `puzzles/day_{name}.md` and each candidate example to
+/// `puzzles/day_{name}_{n}`, returning the written paths.
+pub fn save(year: &Year, day: u8, puzzle: &Puzzle) -> Result<Vec<PathBuf>> {
+	let name = crate::day_name(day).context("day must be between 1 and 25")?;
+	let dir = dir(year);
+	fs::create_dir_all(&dir).with_context(|| format!("couldn't create {}", dir.display()))?;
+	let mut files = vec![(dir.join(format!("day_{name}.md")), &puzzle.markdown)];
+	for (i, example) in puzzle.examples.iter().enumerate() {
+		files.push((dir.join(format!("day_{name}_{}", i + 1)), example));
+	}
+	for (path, content) in &files {
+		fs::write(path, content).with_context(|| format!("couldn't write {}", path.display()))?;
+	}
+	Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn emphasized(article: &str) -> Vec<String> {
+	let mut found = CODE_EM_RE
+		.captures_iter(article)
+		.chain(EM_CODE_RE.captures_iter(article))
+		.map(|c| {
+			(
+				c.get(0).unwrap().start(),
+				decode(&TAG_RE.replace_all(&c[1], "")),
+			)
+		})
+		.collect::<Vec<_>>();
+	found.sort();
+	found.into_iter().map(|(_, answer)| answer).collect()
+}
+
+// Converts the small subset of HTML used in puzzle descriptions, emphasized code is written as
+// bold code so it survives the conversion
+fn to_markdown(html: &str) -> String {
+	let html = CODE_EM_RE.replace_all(html, |c: &Captures| {
+		format!("<em><code>{}</code></em>", &c[1])
+	});
+	let mut out = String::new();
+	let mut pre = false;
+	let mut links = vec![];
+	for token in TOKEN_RE.captures_iter(&html) {
+		if let Some(text) = token.get(4) {
+			let text = decode(text.as_str());
+			if pre {
+				out.push_str(&text);
+			} else {
+				let text = SPACE_RE.replace_all(&text, " ");
+				// Whitespace between block tags would otherwise start lines with a space
+				if !(out.is_empty() || out.ends_with('\n')) || !text.trim().is_empty() {
+					out.push_str(&text);
+				}
+			}
+			continue;
+		}
+		let closing = !token[1].is_empty();
+		match (token[2].to_lowercase().as_str(), closing) {
+			("pre", false) => {
+				pre = true;
+				out.push_str("```\n");
+			}
+			("pre", true) => {
+				pre = false;
+				if !out.ends_with('\n') {
+					out.push('\n');
+				}
+				out.push_str("```\n\n");
+			}
+			(_, _) if pre => (),
+			("h2", false) => out.push_str("## "),
+			("h2" | "p", true) => out.push_str("\n\n"),
+			("em", _) => out.push_str("**"),
+			("code", _) => out.push('`'),
+			("li", false) => out.push_str("- "),
+			("li", true) => out.push('\n'),
+			("ul", true) => out.push('\n'),
+			("a", false) => {
+				links.push(HREF_RE.captures(&token[3]).map(|c| c[1].to_string()));
+				out.push('[');
+			}
+			("a", true) => match links.pop().flatten() {
+				Some(href) => out.push_str(&format!("]({href})")),
+				None => out.push(']'),
+			},
+			_ => (),
+		}
+	}
+	out.trim_end().to_string() + "\n"
+}
+
+fn decode(text: &str) -> String {
+	text
+		.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&apos;", "'")
+		.replace("&amp;", "&")
+}
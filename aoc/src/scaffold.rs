@@ -0,0 +1,182 @@
+use std::{
+	fs::{self, OpenOptions},
+	io::Write,
+	path::PathBuf,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+
+use crate::{day_name, Year};
+
+const DAY_TEMPLATE: &str = "use anyhow::{bail, Result};
+use aoc::Solution;
+
+pub struct {struct};
+
+impl Solution for {struct} {
+	type Input = Vec<String>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(input.lines().map(String::from).collect())
+	}
+
+	fn part_one(_input: &Self::Input) -> Result<usize> {
+		bail!(\"part one isn't solved yet\")
+	}
+
+	fn part_two(_input: &Self::Input) -> Result<usize> {
+		bail!(\"part two isn't solved yet\")
+	}
+}
+";
+
+const BIN_TEMPLATE: &str = "use advent_of_code_{year}::{days::{module}::{struct}, YEAR};
+use anyhow::Result;
+
+fn main() -> Result<()> {
+	aoc::run::<{struct}>(&YEAR, \"{name}\")
+}
+";
+
+/// Creates the files of a new day in the crate of `year` and registers it in its `src/days/mod.rs`.
+/// Years that run their days through binaries of their own, in `src/bin`, get one for the new day.
+///
+/// Nothing is written if any of the files already exists.
+pub fn new_day(year: &Year, day: u8) -> Result<Vec<PathBuf>> {
+	let root = year.root();
+	let name = day_name(day).ok_or_else(|| anyhow!("there is no day {day}"))?;
+	let module = format!("day_{day:02}");
+	let struct_name = format!(
+		"Day{}",
+		name
+			.split('_')
+			.map(|w| w[..1].to_uppercase() + &w[1..])
+			.collect::<String>()
+	);
+	let fill = |template: &str| {
+		template
+			.replace("{struct}", &struct_name)
+			.replace("{module}", &module)
+			.replace("{name}", name)
+			.replace("advent_of_code_{year}", year.package)
+	};
+
+	let mut files = vec![
+		(
+			root.join(format!("src/days/{module}.rs")),
+			fill(DAY_TEMPLATE),
+		),
+		(root.join(format!("input/day_{name}")), String::new()),
+		(
+			root.join(format!("input/examples/day_{name}_1")),
+			String::new(),
+		),
+	];
+	if root.join("src/bin").is_dir() {
+		files.push((
+			root.join(format!("src/bin/day_{name}.rs")),
+			fill(BIN_TEMPLATE),
+		));
+	}
+	let existing = files
+		.iter()
+		.filter(|(path, _)| path.exists())
+		.map(|(path, _)| path.display())
+		.join(", ");
+	if !existing.is_empty() {
+		bail!("refusing to overwrite {existing}");
+	}
+
+	let registry = root.join("src/days/mod.rs");
+	let registered = register(
+		&fs::read_to_string(&registry)
+			.with_context(|| format!("couldn't read {}", registry.display()))?,
+		day,
+		name,
+		&module,
+		&struct_name,
+	)?;
+
+	for (path, content) in &files {
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		OpenOptions::new()
+			.write(true)
+			.create_new(true)
+			.open(path)
+			.and_then(|mut f| f.write_all(content.as_bytes()))
+			.with_context(|| format!("couldn't create {}", path.display()))?;
+	}
+	fs::write(&registry, registered)?;
+
+	Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+// Adds the module declaration and `for_each_day!` entry of a day to the registry's source
+fn register(source: &str, day: u8, name: &str, module: &str, struct_name: &str) -> Result<String> {
+	let declaration = format!("pub mod {module};");
+	if source.lines().any(|l| l == declaration) {
+		bail!("{module} is already registered");
+	}
+	let mut lines = source.lines().map(String::from).collect_vec();
+
+	// Modules are zero-padded, so they sort by day
+	let mods = lines
+		.iter()
+		.positions(|l| l.starts_with("pub mod day_"))
+		.collect_vec();
+	match mods.last() {
+		Some(&last_mod) => {
+			let position = mods
+				.iter()
+				.copied()
+				.find(|&i| lines[i] > declaration)
+				.unwrap_or(last_mod + 1);
+			lines.insert(position, declaration);
+		}
+		// The first day of a year goes after the import of Day
+		None => {
+			let import = lines
+				.iter()
+				.position(|l| l == "use aoc::day::Day;")
+				.ok_or_else(|| anyhow!("couldn't find the day modules or `use aoc::day::Day;`"))?;
+			lines.splice(import + 1..import + 1, [String::new(), declaration]);
+		}
+	}
+
+	let days = lines
+		.iter()
+		.positions(|l| l.contains(" => day_"))
+		.collect_vec();
+	let position = match days.last() {
+		Some(&last_day) => days
+			.iter()
+			.copied()
+			.find(|&i| {
+				lines[i]
+					.trim()
+					.split_once(" => ")
+					.and_then(|(number, _)| number.parse::<u8>().ok())
+					.is_some_and(|number| number > day)
+			})
+			.unwrap_or(last_day + 1),
+		// An empty list only holds the crate
+		None => {
+			lines
+				.iter()
+				.position(|l| l.trim() == "$crate;")
+				.ok_or_else(|| anyhow!("couldn't find the days in for_each_day!"))?
+				+ 1
+		}
+	};
+	lines.insert(
+		position,
+		format!("\t\t\t{day} => {module}::{struct_name}, \"{name}\";"),
+	);
+
+	Ok(lines.join("\n") + "\n")
+}
@@ -0,0 +1,254 @@
+use std::{
+	cell::RefCell,
+	fmt::Display,
+	panic::{self, AssertUnwindSafe},
+	sync::Mutex,
+};
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+
+use crate::{day::Day, diffcheck, panic_message};
+
+// The panic hook is global, so only one input is shrunk at a time
+static SHRINKING: Mutex<()> = Mutex::new(());
+
+thread_local! {
+	// Where the last panic on this thread happened, and its message
+	static LAST_PANIC: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+/// What has to stay true of an input while it's shrunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Predicate {
+	/// The implementations of a part give different answers, or only some of them fail
+	ImplsDiffer,
+	/// Parsing or solving a part panics
+	Panics,
+}
+
+/// Why an input is interesting. Shrinking keeps the same failure, so an input that panics somewhere
+/// else or has another part disagreeing doesn't count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+	Differ { part: u8 },
+	// Only the location has to match, messages often include values from the input
+	Panic { location: String, message: String },
+}
+
+impl Failure {
+	fn matches(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Differ { part }, Self::Differ { part: other }) => part == other,
+			(
+				Self::Panic { location, .. },
+				Self::Panic {
+					location: other, ..
+				},
+			) => location == other,
+			_ => false,
+		}
+	}
+}
+
+impl Display for Failure {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Differ { part } => write!(f, "implementations of part {part} differ"),
+			Self::Panic { location, message } => write!(f, "panic at {location}: {message}"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shrunk {
+	pub input: String,
+	// Of the shrunk input, which matches the failure of the original one
+	pub failure: Failure,
+	// How many candidate inputs were solved
+	pub tests: usize,
+}
+
+/// How `day` fails on `input` according to `predicate`, if it does.
+pub fn failure(day: &Day, input: &str, predicate: Predicate) -> Option<Failure> {
+	let _guard = SHRINKING.lock().unwrap_or_else(|e| e.into_inner());
+	let _hook = QuietHook::install();
+	check(day, input, predicate)
+}
+
+/// Shrinks `input` to a smaller one on which `day` still fails the same way, first removing whole
+/// lines and then tokens within the remaining lines, until neither removes anything.
+///
+/// Candidates are solved in this process, so a day that loops forever on some of them hangs.
+pub fn shrink(day: &Day, input: &str, predicate: Predicate) -> Result<Shrunk> {
+	if predicate == Predicate::ImplsDiffer && !diffcheck::has_alternatives(day) {
+		bail!(
+			"day {} has no alternative implementations to differ, see Solution::implementations",
+			day.day
+		);
+	}
+	let _guard = SHRINKING.lock().unwrap_or_else(|e| e.into_inner());
+	let _hook = QuietHook::install();
+	let Some(original) = check(day, input, predicate) else {
+		bail!(
+			"day {} doesn't fail on the input, the predicate `{}` doesn't hold",
+			day.day,
+			predicate.to_possible_value().unwrap().get_name()
+		);
+	};
+
+	let mut tests = 0;
+	let mut holds = |candidate: &str| {
+		tests += 1;
+		check(day, candidate, predicate).is_some_and(|f| f.matches(&original))
+	};
+	let mut lines: Vec<_> = input.split_inclusive('\n').map(str::to_string).collect();
+	loop {
+		let before = lines.concat();
+		lines = ddmin(lines, &mut |lines| holds(&lines.concat()));
+		for i in 0..lines.len() {
+			let (line, end) = split_line_end(&lines[i]);
+			let (line, end) = (line.to_string(), end.to_string());
+			let mut candidate = lines.clone();
+			let shrunk = ddmin(tokens(&line), &mut |tokens| {
+				candidate[i] = tokens.concat() + &end;
+				holds(&candidate.concat())
+			});
+			lines[i] = shrunk.concat() + &end;
+		}
+		if lines.concat() == before {
+			break;
+		}
+	}
+
+	let input = lines.concat();
+	let failure = check(day, &input, predicate).unwrap_or(original);
+	Ok(Shrunk {
+		input,
+		failure,
+		tests,
+	})
+}
+
+// Parses and solves `input`, catching any panic along the way
+fn check(day: &Day, input: &str, predicate: Predicate) -> Option<Failure> {
+	LAST_PANIC.with(|p| p.borrow_mut().take());
+	let result = panic::catch_unwind(AssertUnwindSafe(|| match predicate {
+		Predicate::ImplsDiffer => diffcheck::compare(day, input)
+			.ok()?
+			.into_iter()
+			.find(|c| !c.agrees())
+			.map(|c| Failure::Differ { part: c.part }),
+		Predicate::Panics => {
+			let parsed = day.parse(input).ok()?;
+			for part in 1..=2 {
+				for (_, solve) in day.implementations(part) {
+					let _ = solve(&parsed);
+				}
+			}
+			None
+		}
+	}));
+	match result {
+		Ok(failure) => failure,
+		Err(payload) if predicate == Predicate::Panics => {
+			let (location, message) = LAST_PANIC
+				.with(|p| p.borrow_mut().take())
+				.unwrap_or_else(|| {
+					(
+						String::from("an unknown location"),
+						panic_message(&*payload),
+					)
+				});
+			Some(Failure::Panic { location, message })
+		}
+		// A panic is a different failure than a disagreement
+		Err(_) => None,
+	}
+}
+
+// Zeller's ddmin: removes ever smaller chunks of `units` while `holds` stays true of the rest,
+// until no single unit can be removed
+fn ddmin(mut units: Vec<String>, holds: &mut impl FnMut(&[String]) -> bool) -> Vec<String> {
+	let mut chunks = 2;
+	while !units.is_empty() {
+		chunks = chunks.min(units.len());
+		let size = units.len().div_ceil(chunks);
+		let removed = (0..units.len()).step_by(size).find_map(|start| {
+			let end = (start + size).min(units.len());
+			let complement = [&units[..start], &units[end..]].concat();
+			holds(&complement).then_some(complement)
+		});
+		match removed {
+			Some(complement) => {
+				units = complement;
+				chunks = (chunks - 1).max(2);
+			}
+			None if chunks == units.len() => break,
+			None => chunks *= 2,
+		}
+	}
+	units
+}
+
+// A line without its line ending, and the line ending
+fn split_line_end(line: &str) -> (&str, &str) {
+	let content = line.trim_end_matches(['\n', '\r']);
+	(content, &line[content.len()..])
+}
+
+// Runs of letters and digits, runs of whitespace and every other character on its own, so that
+// numbers and words are removed whole and brackets or commas one at a time
+fn tokens(line: &str) -> Vec<String> {
+	let class = |c: char| {
+		if c.is_alphanumeric() {
+			Some(true)
+		} else if c.is_whitespace() {
+			Some(false)
+		} else {
+			None
+		}
+	};
+	let mut tokens: Vec<String> = Vec::new();
+	let mut previous = None;
+	for c in line.chars() {
+		match tokens.last_mut() {
+			Some(token) if class(c).is_some() && class(c) == previous => token.push(c),
+			_ => tokens.push(c.to_string()),
+		}
+		previous = class(c);
+	}
+	tokens
+}
+
+type Hook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send>;
+
+// Records panics instead of printing them while installed, the thousands of expected ones would
+// bury everything else
+struct QuietHook {
+	previous: Option<Hook>,
+}
+
+impl QuietHook {
+	fn install() -> Self {
+		let previous = panic::take_hook();
+		panic::set_hook(Box::new(|info| {
+			let location = info
+				.location()
+				.map_or_else(|| String::from("an unknown location"), |l| l.to_string());
+			let message = panic_message(info.payload());
+			LAST_PANIC.with(|p| *p.borrow_mut() = Some((location, message)));
+		}));
+		Self {
+			previous: Some(previous),
+		}
+	}
+}
+
+impl Drop for QuietHook {
+	fn drop(&mut self) {
+		if let Some(previous) = self.previous.take() {
+			panic::set_hook(previous);
+		}
+	}
+}
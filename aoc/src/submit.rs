@@ -0,0 +1,78 @@
+use std::{fmt::Display, time::Duration};
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::client::Client;
+
+lazy_static! {
+	static ref ARTICLE_RE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+	static ref TAG_RE: Regex = Regex::new(r"<[^>]+>").unwrap();
+	static ref WAIT_RE: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+	Correct,
+	TooHigh,
+	TooLow,
+	Wrong,
+	AlreadySolved,
+	RateLimited(Duration),
+}
+
+impl Outcome {
+	// Whether the answer itself was judged wrong, so it should never be submitted again
+	pub fn is_rejection(&self) -> bool {
+		matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+	}
+}
+
+impl Display for Outcome {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Correct => write!(f, "correct"),
+			Self::TooHigh => write!(f, "too high"),
+			Self::TooLow => write!(f, "too low"),
+			Self::Wrong => write!(f, "wrong"),
+			Self::AlreadySolved => write!(f, "already solved"),
+			Self::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+		}
+	}
+}
+
+pub fn submit(client: &Client, day: u8, part: u8, answer: &str) -> Result<Outcome> {
+	let response = client.post(
+		&format!("/{}/day/{day}/answer", client.year),
+		&[("level", &part.to_string()), ("answer", answer)],
+	)?;
+	parse_response(&response)
+}
+
+pub fn parse_response(html: &str) -> Result<Outcome> {
+	let article = ARTICLE_RE
+		.captures(html)
+		.map_or(html, |c| c.get(1).unwrap().as_str());
+	let text = TAG_RE.replace_all(article, "");
+
+	if text.contains("That's the right answer") {
+		Ok(Outcome::Correct)
+	} else if text.contains("your answer is too high") {
+		Ok(Outcome::TooHigh)
+	} else if text.contains("your answer is too low") {
+		Ok(Outcome::TooLow)
+	} else if text.contains("That's not the right answer") {
+		Ok(Outcome::Wrong)
+	} else if text.contains("You don't seem to be solving the right level") {
+		Ok(Outcome::AlreadySolved)
+	} else if let Some(wait) = WAIT_RE.captures(&text) {
+		let minutes = wait.get(1).map_or(Ok(0), |m| m.as_str().parse::<u64>())?;
+		let seconds = wait[2].parse::<u64>()?;
+		Ok(Outcome::RateLimited(Duration::from_secs(
+			minutes * 60 + seconds,
+		)))
+	} else {
+		Err(anyhow!("unrecognised response: {}", text.trim()))
+	}
+}
@@ -0,0 +1,161 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	fs, io,
+	path::Path,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+use anyhow::{Context as _, Result};
+use tracing::{
+	field::{Field, Visit},
+	span, Level, Subscriber,
+};
+use tracing_subscriber::{
+	filter::LevelFilter,
+	fmt::{format::FmtSpan, time::Uptime},
+	layer::{Context, SubscriberExt},
+	registry::LookupSpan,
+	util::SubscriberInitExt,
+	Layer,
+};
+
+/// Level of the spans printed to stderr when `-v` is given `verbosity` times.
+pub fn level(verbosity: u8) -> LevelFilter {
+	match verbosity {
+		0 => LevelFilter::WARN,
+		1 => LevelFilter::INFO,
+		2 => LevelFilter::DEBUG,
+		_ => LevelFilter::TRACE,
+	}
+}
+
+/// Installs the global subscriber, which prints spans with their timings to stderr as they close.
+/// With `profile` every span is also recorded as a folded stack, whatever the verbosity.
+pub fn init(verbosity: u8, profile: bool) -> Option<Folded> {
+	let folded = profile.then(Folded::default);
+	tracing_subscriber::registry()
+		.with(
+			tracing_subscriber::fmt::layer()
+				.with_writer(io::stderr)
+				.with_span_events(FmtSpan::CLOSE)
+				.with_target(false)
+				.with_timer(Uptime::default())
+				.with_filter(level(verbosity)),
+		)
+		.with(folded.clone())
+		.init();
+	folded
+}
+
+/// Records the time spent in every stack of spans, to be written in the folded format read by
+/// flamegraph tools: one `outer;inner nanoseconds` line per stack.
+#[derive(Debug, Clone, Default)]
+pub struct Folded {
+	// Total time spent in each stack, including the time spent in nested spans
+	totals: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+// Stored with every span, trace spans are per iteration so they're merged by name alone
+struct Frame {
+	name: String,
+	entered: Option<Instant>,
+}
+
+// Appends the values of a span's fields to its name, `part{part=1}` becomes `part 1`
+struct FieldValues<'a>(&'a mut String);
+
+impl Visit for FieldValues<'_> {
+	fn record_str(&mut self, _: &Field, value: &str) {
+		self.0.push(' ');
+		self.0.push_str(value);
+	}
+
+	fn record_debug(&mut self, _: &Field, value: &dyn Debug) {
+		self.0.push_str(&format!(" {value:?}"));
+	}
+}
+
+impl<S> Layer<S> for Folded
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+		let mut name = attrs.metadata().name().to_string();
+		if *attrs.metadata().level() != Level::TRACE {
+			attrs.record(&mut FieldValues(&mut name));
+		}
+		// Semicolons separate the frames of a stack
+		let name = name.replace(';', ",");
+		if let Some(span) = ctx.span(id) {
+			span.extensions_mut().insert(Frame {
+				name,
+				entered: None,
+			});
+		}
+	}
+
+	fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id) {
+			if let Some(frame) = span.extensions_mut().get_mut::<Frame>() {
+				frame.entered = Some(Instant::now());
+			}
+		}
+	}
+
+	fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+		let Some(span) = ctx.span(id) else {
+			return;
+		};
+		let Some(entered) = span
+			.extensions_mut()
+			.get_mut::<Frame>()
+			.and_then(|f| f.entered.take())
+		else {
+			return;
+		};
+		let elapsed = entered.elapsed().as_nanos() as u64;
+		let stack = span
+			.scope()
+			.from_root()
+			.filter_map(|s| s.extensions().get::<Frame>().map(|f| f.name.clone()))
+			.collect::<Vec<_>>()
+			.join(";");
+		*self.totals.lock().unwrap().entry(stack).or_default() += elapsed;
+	}
+}
+
+impl Folded {
+	/// The recorded stacks sorted by name, each with the time spent in it outside of nested spans.
+	pub fn folded(&self) -> String {
+		let totals = self.totals.lock().unwrap();
+		let mut stacks = totals
+			.iter()
+			.map(|(stack, total)| {
+				let nested = totals
+					.iter()
+					.filter(|(other, _)| {
+						other
+							.strip_prefix(stack.as_str())
+							.and_then(|rest| rest.strip_prefix(';'))
+							.is_some_and(|rest| !rest.contains(';'))
+					})
+					.map(|(_, time)| time)
+					.sum::<u64>();
+				(stack, total.saturating_sub(nested))
+			})
+			.filter(|(_, time)| *time > 0)
+			.collect::<Vec<_>>();
+		stacks.sort();
+		stacks
+			.into_iter()
+			.map(|(stack, time)| format!("{stack} {time}\n"))
+			.collect()
+	}
+
+	pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		fs::write(path, self.folded()).with_context(|| format!("couldn't write {}", path.display()))
+	}
+}
@@ -0,0 +1,108 @@
+use std::{
+	ffi::OsString,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+
+/// Waits for changes to a set of files using inotify.
+///
+/// The directories holding the files are watched rather than the files themselves, since editors
+/// often save by replacing a file, which would silently end a watch on the original one.
+pub struct Watcher {
+	inotify: Inotify,
+	files: Vec<(WatchDescriptor, OsString)>,
+	buffer: Vec<u8>,
+}
+
+impl Watcher {
+	pub fn new(paths: &[PathBuf]) -> Result<Self> {
+		let inotify = Inotify::init().context("couldn't initialise inotify")?;
+		let mut files = Vec::new();
+		for path in paths {
+			let name = path
+				.file_name()
+				.ok_or_else(|| anyhow!("{} isn't a file", path.display()))?;
+			let dir = match path.parent() {
+				Some(dir) if !dir.as_os_str().is_empty() => dir,
+				_ => Path::new("."),
+			};
+			let wd = inotify
+				.watches()
+				.add(
+					dir,
+					WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::CREATE,
+				)
+				.with_context(|| format!("couldn't watch {}", dir.display()))?;
+			files.push((wd, name.to_os_string()));
+		}
+		Ok(Self {
+			inotify,
+			files,
+			buffer: vec![0; 4096],
+		})
+	}
+
+	/// Blocks until at least one of the watched files was written to or replaced.
+	pub fn wait(&mut self) -> Result<()> {
+		loop {
+			let events = self
+				.inotify
+				.read_events_blocking(&mut self.buffer)
+				.context("couldn't read inotify events")?;
+			let changed = events.into_iter().any(|event| {
+				self
+					.files
+					.iter()
+					.any(|(wd, name)| *wd == event.wd && event.name == Some(name.as_os_str()))
+			});
+			if changed {
+				return Ok(());
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change<'a> {
+	Same(&'a str),
+	Removed(&'a str),
+	Added(&'a str),
+}
+
+/// Line by line difference between two answers, based on their longest common subsequence.
+pub fn diff<'a>(previous: &'a str, current: &'a str) -> Vec<Change<'a>> {
+	let old = previous.lines().collect::<Vec<_>>();
+	let new = current.lines().collect::<Vec<_>>();
+	// Length of the longest common subsequence of old[i..] and new[j..]
+	let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+	for i in (0..old.len()).rev() {
+		for j in (0..new.len()).rev() {
+			lcs[i][j] = if old[i] == new[j] {
+				lcs[i + 1][j + 1] + 1
+			} else {
+				lcs[i + 1][j].max(lcs[i][j + 1])
+			};
+		}
+	}
+
+	let (mut i, mut j) = (0, 0);
+	let mut changes = Vec::new();
+	while i < old.len() && j < new.len() {
+		if old[i] == new[j] {
+			changes.push(Change::Same(old[i]));
+			i += 1;
+			j += 1;
+		} else if lcs[i + 1][j] >= lcs[i][j + 1] {
+			changes.push(Change::Removed(old[i]));
+			i += 1;
+		} else {
+			changes.push(Change::Added(new[j]));
+			j += 1;
+		}
+	}
+	changes.extend(old[i..].iter().map(|l| Change::Removed(l)));
+	changes.extend(new[j..].iter().map(|l| Change::Added(l)));
+	changes
+}
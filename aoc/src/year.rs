@@ -0,0 +1,65 @@
+use std::{
+	env, fmt,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+	config::{self, Config},
+	day::Day,
+	input::INPUT_DIR_VAR,
+};
+
+/// A year of puzzles, registered by the crate holding its days.
+pub struct Year {
+	pub year: u16,
+	// Name of the crate, which code generated for the year refers to
+	pub package: &'static str,
+	// Directory of the crate, holding its aoc.toml, inputs and answers
+	pub root: &'static str,
+	pub days: &'static [Day],
+}
+
+impl fmt::Debug for Year {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Year")
+			.field("year", &self.year)
+			.field("root", &self.root)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Year {
+	pub fn find(&self, day: u8) -> Option<&'static Day> {
+		self.days.iter().find(|d| d.day == day)
+	}
+
+	// Like `find`, for commands that can't do anything with a day that doesn't exist yet
+	pub fn day(&self, day: u8) -> Result<&'static Day> {
+		self
+			.find(day)
+			.ok_or_else(|| anyhow!("day {day} of {} has not been solved yet", self.year))
+	}
+
+	pub fn root(&self) -> &Path {
+		Path::new(self.root)
+	}
+
+	/// The year's configuration, loaded once on first use.
+	pub fn config(&self) -> Result<&'static Config> {
+		config::for_year(self)
+	}
+
+	/// Directory holding the inputs: `AOC_INPUT_DIR` if set, then `input_dir` from aoc.toml,
+	/// falling back to the crate's own `input` directory.
+	pub fn input_dir(&self) -> PathBuf {
+		if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+			return PathBuf::from(dir);
+		}
+		match self.config() {
+			Ok(config) => config.input_dir(),
+			Err(_) => self.root().join("input"),
+		}
+	}
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use aoc::{
+	allocs::{self, Counting, Stats},
+	day::Day,
+	output, Solution,
+};
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+struct Doubling;
+
+impl Solution for Doubling {
+	type Input = Vec<u64>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		let mut numbers = Vec::with_capacity(16);
+		for line in input.lines() {
+			numbers.push(line.parse()?);
+		}
+		Ok(numbers)
+	}
+
+	fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+		Ok(input.len())
+	}
+
+	fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+		let doubled = input.iter().map(|n| n * 2).collect::<Vec<_>>();
+		Ok(doubled.len())
+	}
+}
+
+// A single test, since allocations are counted for the whole process
+#[test]
+fn counts_allocations_per_phase() {
+	let (_, stats) = allocs::measure(|| Vec::<u8>::with_capacity(100));
+	assert_eq!(stats, None);
+
+	allocs::enable().unwrap();
+	let (_, stats) = allocs::measure(|| {
+		let mut grown = Vec::<u8>::with_capacity(100);
+		grown.reserve_exact(200);
+		drop(Vec::<u8>::with_capacity(1000));
+	});
+	assert_eq!(
+		stats,
+		Some(Stats {
+			allocations: 3,
+			bytes: 1300,
+			peak_bytes: 1200,
+		})
+	);
+
+	let day = Day::new::<Doubling>(1, "one");
+	let [one, two] = output::solve_timed(&day, "1\n2\n3\n").unwrap();
+	// The numbers, and the box holding them for both parts
+	let parse = Stats {
+		allocations: 2,
+		bytes: 128 + 24,
+		peak_bytes: 128 + 24,
+	};
+	assert_eq!(one.parse_alloc, Some(parse));
+	assert_eq!(two.parse_alloc, Some(parse));
+	// Answers are allocated as strings as well, in however many bytes std asks for
+	let solve = one.solve_alloc.unwrap();
+	assert_eq!(solve.allocations, 1);
+	assert!(
+		solve.bytes >= 1 && solve.peak_bytes >= solve.bytes,
+		"{solve:?}"
+	);
+	let solve = two.solve_alloc.unwrap();
+	assert_eq!(solve.allocations, 2);
+	// The three doubled numbers, and the answer
+	assert!(solve.bytes > 24, "{solve:?}");
+	assert!(output::csv(&[one]).starts_with(
+		"day,part,answer,parse_ns,solve_ns,parse_allocations,parse_bytes,parse_peak_bytes,"
+	));
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use aoc::answers::Answers;
+
+// The CRT of the day 10 example of 2022
+const CRT: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[test]
+fn keeps_multi_line_answers() {
+	let path = common::temp_dir("answers").join("answers/2022.toml");
+	let mut answers = Answers::default();
+	answers.set(10, 2, "day_ten", CRT.to_string());
+	answers.set(10, 1, "day_ten", String::from("13140"));
+	answers.save(&path).unwrap();
+
+	// Stored as a multi-line string rather than with escaped newlines
+	assert!(std::fs::read_to_string(&path).unwrap().contains(CRT));
+	let answers = Answers::load(&path).unwrap();
+	assert_eq!(answers.get(10, 2, "day_ten"), Some(CRT));
+	assert_eq!(answers.get(10, 1, "day_ten"), Some("13140"));
+	assert_eq!(answers.get(10, 2, "examples/day_ten_1"), None);
+}
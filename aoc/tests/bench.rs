@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use aoc::{
+	bench::{self, Report, Stats},
+	day::Day,
+	Solution,
+};
+
+struct Count;
+
+impl Solution for Count {
+	type Input = usize;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<usize> {
+		Ok(input.lines().count())
+	}
+
+	fn part_one(lines: &usize) -> Result<usize> {
+		Ok(*lines)
+	}
+
+	fn part_two(lines: &usize) -> Result<usize> {
+		Ok(lines * 2)
+	}
+}
+
+fn stats(nanos: &[u64]) -> Stats {
+	let samples = nanos
+		.iter()
+		.copied()
+		.map(Duration::from_nanos)
+		.collect::<Vec<_>>();
+	Stats::from_samples(&samples)
+}
+
+fn as_nanos(stats: Stats) -> [u128; 4] {
+	[stats.min, stats.median, stats.mean, stats.stddev].map(|d| d.as_nanos())
+}
+
+#[test]
+fn summarises_samples() {
+	assert_eq!(as_nanos(stats(&[700])), [700, 700, 700, 0]);
+	// Unsorted, with the median between the two middle samples
+	assert_eq!(as_nanos(stats(&[400, 100, 200, 300])), [100, 250, 250, 111]);
+	assert_eq!(as_nanos(stats(&[9, 1, 5])), [1, 5, 5, 3]);
+}
+
+#[test]
+fn reports_nanoseconds_per_phase() {
+	let day = Day::new::<Count>(3, "three");
+	let report = Report {
+		iterations: 4,
+		warmup: 1,
+		days: vec![bench::bench_day(&day, "a\nb\n", 4, 1).unwrap()],
+	};
+	let json = serde_json::to_value(&report).unwrap();
+	assert_eq!(json["iterations"], 4);
+	assert_eq!(json["warmup"], 1);
+	let days = json["days"].as_array().unwrap();
+	assert_eq!(days.len(), 1);
+	assert_eq!(days[0]["day"], 3);
+	for phase in ["parse", "part_one", "part_two"] {
+		let stats = days[0][phase].as_object().unwrap();
+		let keys = stats.keys().map(String::as_str).collect::<Vec<_>>();
+		assert_eq!(
+			keys,
+			["mean_ns", "median_ns", "min_ns", "stddev_ns"],
+			"{phase}"
+		);
+		assert!(stats.values().all(serde_json::Value::is_u64), "{phase}");
+	}
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use aoc::client::{Client, USER_AGENT};
+use common::Stub;
+
+#[test]
+fn downloads_input_once_and_caches_it() {
+	let stub = Stub::serve(vec![(200, String::from("1\n2\n3\n"))]);
+	let client = Client::new(&stub.url, "secret", 2022);
+	let path = common::temp_dir("client").join("input/day_one");
+
+	assert_eq!(client.cached_input(1, &path).unwrap(), "1\n2\n3\n");
+	assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+	// The stub only answers once, so this has to come from the cache
+	assert_eq!(client.cached_input(1, &path).unwrap(), "1\n2\n3\n");
+
+	let requests = stub.requests();
+	assert_eq!(requests.len(), 1);
+	assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+	assert!(requests[0].contains("session=secret"));
+	assert!(requests[0].contains(&format!("{USER_AGENT}\r\n")));
+}
+
+#[test]
+fn downloads_over_empty_placeholders() {
+	let stub = Stub::serve(vec![(200, String::from("4\n"))]);
+	let client = Client::new(&stub.url, "secret", 2022);
+	let path = common::temp_dir("client-empty").join("day_two");
+	std::fs::write(&path, "").unwrap();
+	assert!(!aoc::input::is_saved(&path));
+
+	assert_eq!(client.cached_input(2, &path).unwrap(), "4\n");
+	assert!(aoc::input::is_saved(&path));
+	assert_eq!(stub.requests().len(), 1);
+}
+
+#[test]
+fn reports_unavailable_inputs() {
+	let stub = Stub::serve(vec![(404, String::from("Not found"))]);
+	let client = Client::new(&stub.url, "secret", 2022);
+	let path = common::temp_dir("client-404").join("day_twenty_five");
+
+	let err = client.cached_input(25, &path).unwrap_err();
+	assert!(err.to_string().contains("isn't available"));
+	assert!(!path.exists());
+	stub.requests();
+}
@@ -0,0 +1,127 @@
+#![allow(dead_code)]
+
+use std::{
+	io::{BufRead, BufReader, Read, Write},
+	net::TcpListener,
+	sync::mpsc::{self, Receiver},
+	thread::{self, JoinHandle},
+};
+
+use anyhow::{bail, Result};
+use aoc::{Implementation, Solution};
+
+// Local HTTP server that answers each connection with the next canned response and records the requests
+pub struct Stub {
+	pub url: String,
+	requests: Receiver<String>,
+	handle: JoinHandle<()>,
+}
+
+impl Stub {
+	pub fn serve(responses: Vec<(u16, String)>) -> Self {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+		let (tx, requests) = mpsc::channel();
+		let handle = thread::spawn(move || {
+			for (status, body) in responses {
+				let (stream, _) = listener.accept().unwrap();
+				let mut reader = BufReader::new(stream);
+				let mut request = String::new();
+				let mut content_length = 0;
+				loop {
+					let mut line = String::new();
+					reader.read_line(&mut line).unwrap();
+					if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+						content_length = len.trim().parse().unwrap();
+					}
+					request.push_str(&line);
+					if line == "\r\n" || line.is_empty() {
+						break;
+					}
+				}
+				let mut body_buf = vec![0; content_length];
+				reader.read_exact(&mut body_buf).unwrap();
+				request.push_str(&String::from_utf8(body_buf).unwrap());
+				tx.send(request).unwrap();
+				write!(
+					reader.get_mut(),
+					"HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+					body.len()
+				)
+				.unwrap();
+			}
+		});
+		Self {
+			url,
+			requests,
+			handle,
+		}
+	}
+
+	// Waits for every canned response to be served and returns the requests that were made
+	pub fn requests(self) -> Vec<String> {
+		self.handle.join().unwrap();
+		self.requests.try_iter().collect()
+	}
+}
+
+pub fn temp_dir(name: &str) -> std::path::PathBuf {
+	let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(&dir).unwrap();
+	dir
+}
+
+// Sums numbers in part one and counts them in part two, with alternative implementations that
+// agree with those on some inputs but not others
+pub struct Sum;
+
+impl Solution for Sum {
+	type Input = Vec<u32>;
+	type PartOne = u32;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+	}
+
+	fn part_one(input: &Self::Input) -> Result<u32> {
+		Ok(input.iter().sum())
+	}
+
+	fn part_two(input: &Self::Input) -> Result<usize> {
+		Ok(input.len())
+	}
+
+	fn implementations() -> Vec<Implementation<Self::Input>> {
+		vec![
+			Implementation {
+				name: "reversed",
+				part: 1,
+				solve: |input| Ok(input.iter().rev().sum::<u32>().to_string()),
+			},
+			// Forgets the last number, which only matters when it isn't zero
+			Implementation {
+				name: "broken",
+				part: 1,
+				solve: |input| {
+					Ok(
+						input[..input.len().saturating_sub(1)]
+							.iter()
+							.sum::<u32>()
+							.to_string(),
+					)
+				},
+			},
+			// Only copes with a single number
+			Implementation {
+				name: "failing",
+				part: 2,
+				solve: |input| match input.len() {
+					0 | 1 => Ok(input.len().to_string()),
+					_ => bail!("not implemented"),
+				},
+			},
+		]
+	}
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use anyhow::Result;
+use aoc::{
+	day::{Day, DEFAULT_IMPL},
+	diffcheck::{self, Comparison},
+	output, Solution,
+};
+use common::Sum;
+
+const DAY: Day = Day::new::<Sum>(1, "one");
+
+struct Plain;
+
+impl Solution for Plain {
+	type Input = ();
+	type PartOne = u8;
+	type PartTwo = u8;
+
+	fn parse(_: &str) -> Result<()> {
+		Ok(())
+	}
+
+	fn part_one(_: &()) -> Result<u8> {
+		Ok(1)
+	}
+
+	fn part_two(_: &()) -> Result<u8> {
+		Ok(2)
+	}
+}
+
+#[test]
+fn lists_implementations_per_part() {
+	let names = |part| {
+		DAY
+			.implementations(part)
+			.into_iter()
+			.map(|(name, _)| name)
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(names(1), [DEFAULT_IMPL, "reversed", "broken"]);
+	assert_eq!(names(2), [DEFAULT_IMPL, "failing"]);
+	assert!(diffcheck::has_alternatives(&DAY));
+	assert!(!diffcheck::has_alternatives(&Day::new::<Plain>(2, "two")));
+}
+
+#[test]
+fn selects_implementations_by_name() {
+	let [one, two] = output::solve_timed_with(&DAY, "1\n2\n3\n", "broken").unwrap();
+	assert_eq!(one.answer, "3");
+	// Part two has no implementation by that name
+	assert_eq!(two.answer, "3");
+
+	let err = output::solve_timed_with(&DAY, "1\n", "fast").unwrap_err();
+	assert_eq!(
+		err.to_string(),
+		"day 1 has no implementation called fast, only default, reversed, broken, failing"
+	);
+}
+
+#[test]
+fn flags_disagreements() {
+	let comparisons = diffcheck::compare(&DAY, "1\n2\n3\n").unwrap();
+	assert_eq!(
+		comparisons,
+		[
+			Comparison {
+				part: 1,
+				answers: vec![
+					(DEFAULT_IMPL, Ok(String::from("6"))),
+					("reversed", Ok(String::from("6"))),
+					("broken", Ok(String::from("3"))),
+				],
+			},
+			Comparison {
+				part: 2,
+				answers: vec![
+					(DEFAULT_IMPL, Ok(String::from("3"))),
+					("failing", Err(String::from("not implemented"))),
+				],
+			},
+		]
+	);
+	assert!(comparisons.iter().all(|c| !c.agrees()));
+
+	// A single number is the only input the broken implementation gets right
+	let comparisons = diffcheck::compare(&DAY, "0\n").unwrap();
+	assert!(comparisons[0].agrees());
+}
+
+#[test]
+fn agrees_when_all_fail_the_same_way() {
+	let comparison = |errors: [&str; 2]| Comparison {
+		part: 1,
+		answers: vec![
+			(DEFAULT_IMPL, Err(String::from(errors[0]))),
+			("other", Err(String::from(errors[1]))),
+		],
+	};
+	assert!(comparison(["no path", "no path"]).agrees());
+	assert!(!comparison(["no path", "empty input"]).agrees());
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use std::fs;
+
+use aoc::{input::InputSource, Year};
+
+// The message of an error loading the input of `day` from `source`, which has to fail
+fn error(source: &InputSource, day: &str) -> String {
+	source.load(day).unwrap_err().to_string()
+}
+
+#[test]
+fn names_missing_files_and_how_to_fix_them() {
+	let dir = common::temp_dir("input-missing");
+	let path = dir.join("day_one");
+
+	let message = error(&InputSource::File(path.clone()), "one");
+	assert!(
+		message.starts_with(&format!("couldn't read the input at {}: ", path.display())),
+		"{message}"
+	);
+	assert!(
+		message.ends_with("\ncheck the path passed to --input"),
+		"{message}"
+	);
+
+	let message = error(&InputSource::Dir(dir.clone()), "one");
+	assert!(
+		message.starts_with(&format!(
+			"couldn't read the input of day one at {}: ",
+			path.display()
+		)),
+		"{message}"
+	);
+	assert!(
+		message.ends_with(
+			"\ndownload it with `aoc fetch 1`, or point --input-dir or AOC_INPUT_DIR at the directory \
+			 containing day_one"
+		),
+		"{message}"
+	);
+}
+
+#[test]
+fn points_at_the_year_or_its_config() {
+	let root = common::temp_dir("input-year");
+	fs::write(
+		root.join("aoc.toml"),
+		"[day.3]\ninput = \"elsewhere/day_three\"\n",
+	)
+	.unwrap();
+	// Configurations are cached by year, so this one can't be used by any other test
+	let year: &'static Year = Box::leak(Box::new(Year {
+		year: 2015,
+		package: "advent_of_code_2015",
+		root: Box::leak(root.to_string_lossy().into_owned().into_boxed_str()),
+		days: &[],
+	}));
+	let source = InputSource::Year(year);
+
+	let message = error(&source, "four");
+	assert!(
+		message.starts_with(&format!(
+			"couldn't read the input of day four at {}: ",
+			root.join("input/day_four").display()
+		)),
+		"{message}"
+	);
+	assert!(
+		message.contains("\ndownload it with `aoc fetch --year 2015 4`"),
+		"{message}"
+	);
+
+	let message = error(&source, "three");
+	assert!(
+		message.starts_with(&format!(
+			"couldn't read the input of day three at {}: ",
+			root.join("elsewhere/day_three").display()
+		)),
+		"{message}"
+	);
+	assert!(
+		message.ends_with("\ncheck [day.3] in aoc.toml"),
+		"{message}"
+	);
+}
+
+#[test]
+fn says_how_to_save_missing_examples() {
+	let path = common::temp_dir("input-example").join("examples/day_three_9");
+	let message = error(&InputSource::Example(9, path.clone()), "three");
+	assert!(
+		message.starts_with(&format!(
+			"couldn't read example 9 of day three at {}: ",
+			path.display()
+		)),
+		"{message}"
+	);
+	assert!(
+		message.ends_with(
+			"\nsave the example there, `aoc read 3` writes the candidates it finds in the puzzle to \
+			 puzzles/"
+		),
+		"{message}"
+	);
+}
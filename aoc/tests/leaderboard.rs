@@ -0,0 +1,108 @@
+mod common;
+
+use std::collections::HashMap;
+
+use aoc::{
+	client::Client,
+	leaderboard::{self, DayResult, Leaderboard},
+};
+use common::Stub;
+
+const JSON: &str = include_str!("fixtures/leaderboard.json");
+
+#[test]
+fn parses_export() {
+	let board = Leaderboard::parse(JSON).unwrap();
+	assert_eq!(board.year().unwrap(), 2022);
+	assert_eq!(board.members.len(), 4);
+	assert_eq!(board.members["3"].display_name(), "(anonymous user #3)");
+	assert_eq!(
+		board.members["2"].star(2, 1).unwrap().get_star_ts,
+		1669957380
+	);
+	assert!(board.members["2"].star(2, 2).is_none());
+	assert_eq!(board.unlock(1).unwrap(), 1669870800);
+	assert!(Leaderboard::parse("{\"event\": \"2022\"}").is_err());
+}
+
+#[test]
+fn recalculates_local_scores() {
+	let board = Leaderboard::parse(JSON).unwrap();
+	assert_eq!(
+		board.local_scores(),
+		HashMap::from([(1, 14), (2, 11), (3, 2), (4, 0)])
+	);
+	for member in board.members.values() {
+		assert_eq!(board.local_scores()[&member.id], member.local_score);
+	}
+}
+
+#[test]
+fn ranks_days() {
+	let board = Leaderboard::parse(JSON).unwrap();
+	assert_eq!(
+		board.day_ranking(1),
+		vec![
+			DayResult {
+				member: 1,
+				part_one: Some(1669871100),
+				part_two: Some(1669871400),
+				points: 7,
+			},
+			DayResult {
+				member: 2,
+				part_one: Some(1669870980),
+				part_two: Some(1669872000),
+				points: 7,
+			},
+			DayResult {
+				member: 3,
+				part_one: Some(1669871100),
+				part_two: None,
+				points: 2,
+			},
+		]
+	);
+	assert_eq!(board.day_ranking(1)[1].delta(), Some(1020));
+	assert_eq!(board.day_ranking(1)[2].delta(), None);
+	assert!(board.day_ranking(3).is_empty());
+}
+
+#[test]
+fn prints_tables() {
+	let board = Leaderboard::parse(JSON).unwrap();
+	assert_eq!(
+		board.standings(),
+		"\
+Rank | Member              | Score | Site  | Stars | 1234567890123456789012345
+-----+---------------------+-------+-------+-------+--------------------------
+   1 | Ada                 |    14 |    14 |     4 | **
+   2 | Grace               |    11 |    11 |     3 | *.
+   3 | (anonymous user #3) |     2 |     2 |     1 | .
+   4 | Linus               |     0 |     0 |     0 |
+"
+	);
+	assert_eq!(
+		board.day_table(1).unwrap(),
+		"\
+Rank | Member              |     Part 1 |     Part 2 |      Delta | Points
+-----+---------------------+------------+------------+------------+-------
+   1 | Ada                 |    0:05:00 |    0:10:00 |    0:05:00 |      7
+   2 | Grace               |    0:03:00 |    0:20:00 |    0:17:00 |      7
+   3 | (anonymous user #3) |    0:05:00 |          - |          - |      2
+"
+	);
+}
+
+#[test]
+fn fetches_private_leaderboard() {
+	let stub = Stub::serve(vec![(200, JSON.to_string())]);
+	let client = Client::new(&stub.url, "secret", 2022);
+
+	let board = leaderboard::fetch(&client, 123456).unwrap();
+	assert_eq!(board.owner_id, 1);
+
+	let requests = stub.requests();
+	assert!(requests[0].starts_with("GET /2022/leaderboard/private/view/123456.json HTTP/1.1"));
+	assert!(requests[0].contains("session=secret"));
+}
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use aoc::output::{self, Entry};
+
+fn entries() -> Vec<Entry> {
+	vec![
+		Entry {
+			day: 10,
+			part: 1,
+			answer: String::from("13140"),
+			parse: Duration::from_nanos(1500),
+			solve: Duration::from_nanos(200),
+			parse_alloc: None,
+			solve_alloc: None,
+		},
+		Entry {
+			day: 10,
+			part: 2,
+			answer: String::from("##..\n#\"#,"),
+			parse: Duration::from_nanos(1500),
+			solve: Duration::from_nanos(300),
+			parse_alloc: None,
+			solve_alloc: None,
+		},
+	]
+}
+
+#[test]
+fn json_keeps_multi_line_answers() {
+	let json: serde_json::Value = serde_json::from_str(&output::json(&entries()).unwrap()).unwrap();
+	assert_eq!(json[1]["answer"], "##..\n#\"#,");
+	assert_eq!(json[1]["parse_ns"], 1500);
+	assert_eq!(json[1]["solve_ns"], 300);
+}
+
+#[test]
+fn csv_quotes_answers() {
+	assert_eq!(
+		output::csv(&entries()),
+		"day,part,answer,parse_ns,solve_ns\n\
+		10,1,\"13140\",1500,200\n\
+		10,2,\"##..\n#\"\"#,\",1500,300\n"
+	);
+}
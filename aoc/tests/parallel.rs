@@ -0,0 +1,38 @@
+use std::{
+	num::NonZeroUsize,
+	sync::atomic::{AtomicUsize, Ordering},
+	thread,
+	time::Duration,
+};
+
+use aoc::parallel;
+
+fn jobs(n: usize) -> NonZeroUsize {
+	NonZeroUsize::new(n).unwrap()
+}
+
+#[test]
+fn keeps_the_order_of_items() {
+	// Earlier items take longer, so they finish last
+	let items = (0..8u64).collect::<Vec<_>>();
+	let results = parallel::map(&items, jobs(4), |i| {
+		thread::sleep(Duration::from_millis(2 * (8 - i)));
+		i * 10
+	});
+	assert_eq!(results, [0, 10, 20, 30, 40, 50, 60, 70]);
+}
+
+#[test]
+fn maps_every_item_once() {
+	let calls = AtomicUsize::new(0);
+	let items = (0..100).collect::<Vec<_>>();
+	let results = parallel::map(&items, jobs(16), |i| {
+		calls.fetch_add(1, Ordering::Relaxed);
+		i + 1
+	});
+	assert_eq!(calls.into_inner(), 100);
+	assert_eq!(results, (1..=100).collect::<Vec<_>>());
+
+	assert!(parallel::map(&[] as &[u8], jobs(4), |i| *i).is_empty());
+	assert_eq!(parallel::map(&["a"], jobs(1), |s| s.len()), [1]);
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use aoc::{
+	output::Entry,
+	progress::{self, DayStatus, Progress},
+};
+
+fn entry(day: u8, part: u8, answer: &str) -> Entry {
+	Entry {
+		day,
+		part,
+		answer: answer.to_string(),
+		parse: Duration::from_nanos(100),
+		solve: Duration::from_nanos(200),
+		parse_alloc: None,
+		solve_alloc: None,
+	}
+}
+
+#[test]
+fn verifies_against_the_expected_answer() {
+	let mut progress = Progress::default();
+	progress.record(&entry(1, 1, "24000"), Some("24000"));
+	progress.record(&entry(1, 2, "45000"), None);
+	progress.record(&entry(2, 1, "15"), Some("12"));
+
+	assert!(progress.get(1, 1).unwrap().is_verified());
+	assert!(!progress.get(1, 2).unwrap().is_verified());
+	assert!(!progress.get(2, 1).unwrap().is_verified());
+
+	progress.record(&entry(2, 1, "12"), Some("12"));
+	assert!(progress.get(2, 1).unwrap().is_verified());
+	assert_eq!(progress.get(2, 1).unwrap().solve_ns, 200);
+}
+
+#[test]
+fn draws_the_calendar_from_the_first_weekday() {
+	let progress = {
+		let mut progress = Progress::default();
+		progress.record(&entry(1, 1, "1"), Some("1"));
+		progress.record(&entry(1, 2, "2"), Some("2"));
+		progress.record(&entry(2, 1, "3"), Some("3"));
+		progress
+	};
+	let days = (1..=25)
+		.map(|day| DayStatus {
+			day,
+			solved: day <= 3,
+			input: day <= 3,
+			parts: (1..=2)
+				.filter_map(|part| progress.get(day, part).cloned())
+				.collect(),
+		})
+		.collect::<Vec<_>>();
+
+	// December 2022 started on a Thursday
+	let calendar = progress::calendar(2022, &days);
+	let lines = calendar.lines().collect::<Vec<_>>();
+	assert_eq!(lines[0], " Mo     Tu     We     Th     Fr     Sa     Su");
+	assert_eq!(lines[1], "                      1 **    2 *     3 ..    4");
+	assert_eq!(
+		lines[2],
+		" 5       6       7       8       9      10      11"
+	);
+	assert_eq!(
+		lines.last(),
+		Some(&"19      20      21      22      23      24      25")
+	);
+}
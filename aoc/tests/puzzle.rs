@@ -0,0 +1,73 @@
+mod common;
+
+use aoc::{
+	client::Client,
+	puzzle::{self, Puzzle},
+};
+use common::Stub;
+
+const PAGE: &str = include_str!("fixtures/day_1.html");
+
+#[test]
+fn converts_articles_to_markdown() {
+	let puzzle = Puzzle::parse(PAGE).unwrap();
+	assert_eq!(
+		puzzle.markdown,
+		"## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of **Calories** contained by the various snacks they've brought with them, one item per line.
+
+For example, suppose the Elves finish writing their items' Calories and end up with the following list:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories, a total of **`3000`** Calories.
+- The second Elf is carrying one food item with **`4000`** Calories.
+
+Find the Elf carrying the most Calories & say how many they carry. In the example above, this is **`4000`**.
+
+See [your puzzle input](/2022/day/1/input).
+
+## --- Part Two ---
+
+Find the top **three** Elves carrying the most Calories, for `x < y`.
+
+In the example above, the total is **`7000`**.
+"
+	);
+}
+
+#[test]
+fn extracts_examples_and_answers() {
+	let puzzle = Puzzle::parse(PAGE).unwrap();
+	assert_eq!(puzzle.examples, vec!["1000\n2000\n\n4000\n"]);
+	assert_eq!(
+		puzzle.answers,
+		vec![vec!["3000", "4000", "4000"], vec!["7000"]]
+	);
+}
+
+#[test]
+fn rejects_locked_days() {
+	assert!(Puzzle::parse("<html><body><main><p>Please don't repeatedly request this endpoint before it unlocks!</p></main></body></html>").is_err());
+}
+
+#[test]
+fn fetches_day_page() {
+	let stub = Stub::serve(vec![(200, PAGE.to_string())]);
+	let client = Client::new(&stub.url, "secret", 2022);
+
+	assert_eq!(
+		puzzle::fetch(&client, 1).unwrap(),
+		Puzzle::parse(PAGE).unwrap()
+	);
+
+	let requests = stub.requests();
+	assert!(requests[0].starts_with("GET /2022/day/1 HTTP/1.1"));
+	assert!(requests[0].contains("session=secret"));
+}
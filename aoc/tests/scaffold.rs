@@ -0,0 +1,164 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use aoc::{scaffold, Year};
+
+const REGISTRY: &str = "use aoc::day::Day;
+
+pub mod day_05;
+pub mod day_10;
+
+#[macro_export]
+macro_rules! for_each_day {
+	($($callback:tt)*) => {
+		$($callback)*! {
+			$crate;
+			5 => day_05::DayFive, \"five\";
+			10 => day_10::DayTen, \"ten\";
+		}
+	};
+}
+";
+
+// The registry of a year without any days yet
+const EMPTY_REGISTRY: &str = "use aoc::day::Day;
+
+#[macro_export]
+macro_rules! for_each_day {
+	($($callback:tt)*) => {
+		$($callback)*! {
+			$crate;
+		}
+	};
+}
+";
+
+// A year crate in a temporary directory, registering days 5 and 10
+fn year(name: &str) -> Year {
+	year_with(name, REGISTRY)
+}
+
+fn year_with(name: &str, registry: &str) -> Year {
+	let root = common::temp_dir(name);
+	fs::create_dir_all(root.join("src/days")).unwrap();
+	fs::write(root.join("src/days/mod.rs"), registry).unwrap();
+	Year {
+		year: 2021,
+		package: "advent_of_code_2021",
+		root: Box::leak(root.to_string_lossy().into_owned().into_boxed_str()),
+		days: &[],
+	}
+}
+
+fn registry(year: &Year) -> String {
+	fs::read_to_string(year.root().join("src/days/mod.rs")).unwrap()
+}
+
+#[test]
+fn registers_days_in_order() {
+	let year = year("scaffold-order");
+	for day in [1, 7, 12] {
+		scaffold::new_day(&year, day).unwrap();
+	}
+	let registry = registry(&year);
+	let modules = registry
+		.lines()
+		.filter(|l| l.starts_with("pub mod"))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		modules,
+		[
+			"pub mod day_01;",
+			"pub mod day_05;",
+			"pub mod day_07;",
+			"pub mod day_10;",
+			"pub mod day_12;"
+		]
+	);
+	let days = registry
+		.lines()
+		.filter(|l| l.contains(" => day_"))
+		.map(str::trim)
+		.collect::<Vec<_>>();
+	assert_eq!(
+		days,
+		[
+			"1 => day_01::DayOne, \"one\";",
+			"5 => day_05::DayFive, \"five\";",
+			"7 => day_07::DaySeven, \"seven\";",
+			"10 => day_10::DayTen, \"ten\";",
+			"12 => day_12::DayTwelve, \"twelve\";"
+		]
+	);
+}
+
+#[test]
+fn registers_the_first_day_of_a_year() {
+	let year = year_with("scaffold-first", EMPTY_REGISTRY);
+	scaffold::new_day(&year, 1).unwrap();
+	assert_eq!(
+		registry(&year),
+		"use aoc::day::Day;
+
+pub mod day_01;
+
+#[macro_export]
+macro_rules! for_each_day {
+	($($callback:tt)*) => {
+		$($callback)*! {
+			$crate;
+			1 => day_01::DayOne, \"one\";
+		}
+	};
+}
+"
+	);
+}
+
+#[test]
+fn creates_the_files_of_a_day() {
+	let year = year("scaffold-files");
+	fs::create_dir_all(year.root().join("src/bin")).unwrap();
+	let files = scaffold::new_day(&year, 12).unwrap();
+	let relative = files
+		.iter()
+		.map(|f| f.strip_prefix(year.root()).unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(
+		relative,
+		[
+			Path::new("src/days/day_12.rs"),
+			Path::new("input/day_twelve"),
+			Path::new("input/examples/day_twelve_1"),
+			Path::new("src/bin/day_twelve.rs"),
+		]
+	);
+	let solution = fs::read_to_string(&files[0]).unwrap();
+	assert!(solution.contains("impl Solution for DayTwelve"));
+	assert_eq!(fs::read_to_string(&files[1]).unwrap(), "");
+	let binary = fs::read_to_string(&files[3]).unwrap();
+	assert!(binary.contains("use advent_of_code_2021::{days::day_12::DayTwelve, YEAR};"));
+	assert!(binary.contains("aoc::run::<DayTwelve>(&YEAR, \"twelve\")"));
+}
+
+#[test]
+fn refuses_to_overwrite_existing_files() {
+	let year = year("scaffold-existing");
+	let input = year.root().join("input/day_three");
+	fs::create_dir_all(input.parent().unwrap()).unwrap();
+	fs::write(&input, "my input\n").unwrap();
+
+	let error = scaffold::new_day(&year, 3).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		format!("refusing to overwrite {}", input.display())
+	);
+	assert_eq!(fs::read_to_string(&input).unwrap(), "my input\n");
+	assert!(!year.root().join("src/days/day_03.rs").exists());
+	assert_eq!(registry(&year), REGISTRY);
+
+	// Registered days are refused as well, even once their files are gone
+	let error = scaffold::new_day(&year, 5).unwrap_err();
+	assert_eq!(error.to_string(), "day_05 is already registered");
+}
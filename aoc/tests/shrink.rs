@@ -0,0 +1,81 @@
+mod common;
+
+use anyhow::Result;
+use aoc::{
+	day::Day,
+	shrink::{self, Failure, Predicate},
+	Solution,
+};
+use common::Sum;
+
+struct Boom;
+
+impl Solution for Boom {
+	type Input = Vec<String>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		Ok(input.lines().map(str::to_string).collect())
+	}
+
+	// Panics on an empty input, which is a different failure than the one being shrunk
+	fn part_one(input: &Self::Input) -> Result<usize> {
+		Ok(input[0].len())
+	}
+
+	fn part_two(input: &Self::Input) -> Result<usize> {
+		if input
+			.iter()
+			.any(|line| line.split(' ').any(|word| word == "boom"))
+		{
+			panic!("boom");
+		}
+		Ok(input.len())
+	}
+}
+
+#[test]
+fn shrinks_while_implementations_differ() {
+	let day = Day::new::<Sum>(1, "one");
+	let shrunk = shrink::shrink(&day, "1\n0\n2\n0\n3\n4\n", Predicate::ImplsDiffer).unwrap();
+	assert_eq!(shrunk.input, "4\n");
+	assert_eq!(shrunk.failure, Failure::Differ { part: 1 });
+	assert!(shrunk.tests > 0);
+}
+
+#[test]
+fn shrinks_lines_and_tokens_to_the_same_panic() {
+	let day = Day::new::<Boom>(1, "one");
+	let shrunk = shrink::shrink(&day, "a b\nc boom d\n\ne\n", Predicate::Panics).unwrap();
+	assert_eq!(shrunk.input, "boom\n");
+	let Failure::Panic { location, message } = shrunk.failure else {
+		panic!("expected a panic, got {:?}", shrunk.failure);
+	};
+	assert!(location.starts_with("aoc/tests/shrink.rs:"), "{location}");
+	assert_eq!(message, "boom");
+}
+
+#[test]
+fn checks_the_predicate_without_shrinking() {
+	let day = Day::new::<Boom>(1, "one");
+	assert_eq!(shrink::failure(&day, "a\n", Predicate::Panics), None);
+	assert!(matches!(
+		shrink::failure(&day, "", Predicate::Panics),
+		Some(Failure::Panic { .. })
+	));
+}
+
+#[test]
+fn fails_when_the_predicate_does_not_hold() {
+	let day = Day::new::<Sum>(1, "one");
+	let error = shrink::shrink(&day, "0\n", Predicate::ImplsDiffer).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"day 1 doesn't fail on the input, the predicate `impls-differ` doesn't hold"
+	);
+
+	let day = Day::new::<Boom>(1, "one");
+	let error = shrink::shrink(&day, "boom\n", Predicate::ImplsDiffer).unwrap_err();
+	assert!(error.to_string().contains("no alternative implementations"));
+}
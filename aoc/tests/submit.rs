@@ -0,0 +1,50 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc::{
+	client::Client,
+	submit::{self, Outcome},
+};
+use common::Stub;
+
+fn article(text: &str) -> String {
+	format!("<html><body><main><article><p>{text} <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main></body></html>")
+}
+
+#[test]
+fn classifies_responses() {
+	let cases = [
+		("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.", Outcome::Correct),
+		("That's not the right answer; your answer is too high.  Please wait one minute before trying again.", Outcome::TooHigh),
+		("That's not the right answer; your answer is too low.  Please wait one minute before trying again.", Outcome::TooLow),
+		("That's not the right answer.  If you're stuck, make sure you're using the full input data.", Outcome::Wrong),
+		("You don't seem to be solving the right level.  Did you already complete it?", Outcome::AlreadySolved),
+		("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait.", Outcome::RateLimited(Duration::from_secs(62))),
+		("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 35s left to wait.", Outcome::RateLimited(Duration::from_secs(35))),
+	];
+	for (text, outcome) in cases {
+		assert_eq!(
+			submit::parse_response(&article(text)).unwrap(),
+			outcome,
+			"{text}"
+		);
+	}
+	assert!(submit::parse_response(&article("Something else entirely")).is_err());
+}
+
+#[test]
+fn posts_answer_form() {
+	let stub = Stub::serve(vec![(200, article("That's the right answer!"))]);
+	let client = Client::new(&stub.url, "secret", 2022);
+
+	assert_eq!(
+		submit::submit(&client, 12, 2, "354").unwrap(),
+		Outcome::Correct
+	);
+
+	let requests = stub.requests();
+	assert!(requests[0].starts_with("POST /2022/day/12/answer HTTP/1.1"));
+	assert!(requests[0].contains("session=secret"));
+	assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=354"));
+}
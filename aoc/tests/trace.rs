@@ -0,0 +1,47 @@
+use std::{thread, time::Duration};
+
+use aoc::trace::Folded;
+use tracing::{info_span, trace_span};
+use tracing_subscriber::layer::SubscriberExt;
+
+fn stacks(folded: &Folded) -> Vec<(String, u64)> {
+	folded
+		.folded()
+		.lines()
+		.map(|line| {
+			let (stack, time) = line.rsplit_once(' ').unwrap();
+			(stack.to_string(), time.parse().unwrap())
+		})
+		.collect()
+}
+
+#[test]
+fn folds_nested_spans() {
+	let folded = Folded::default();
+	let subscriber = tracing_subscriber::registry().with(folded.clone());
+	tracing::subscriber::with_default(subscriber, || {
+		let _day = info_span!("day", day = 11).entered();
+		thread::sleep(Duration::from_millis(5));
+		for round in 0..3 {
+			let _round = trace_span!("round", round).entered();
+			thread::sleep(Duration::from_millis(2));
+		}
+	});
+
+	let stacks = stacks(&folded);
+	let names = stacks.iter().map(|(s, _)| s.as_str()).collect::<Vec<_>>();
+	// Trace spans are merged whatever their fields
+	assert_eq!(names, ["day 11", "day 11;round"]);
+	// Only lower bounds, a sleep can take any amount longer on a busy machine
+	assert!(stacks[0].1 >= 5_000_000);
+	assert!(stacks[1].1 >= 6_000_000);
+}
+
+#[test]
+fn records_nothing_without_spans() {
+	let folded = Folded::default();
+	tracing::subscriber::with_default(tracing_subscriber::registry().with(folded.clone()), || {
+		tracing::info!("not a span");
+	});
+	assert_eq!(folded.folded(), "");
+}
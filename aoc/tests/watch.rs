@@ -0,0 +1,37 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::{fs, thread, time::Duration};
+
+use aoc::watch::{self, Change, Watcher};
+
+#[test]
+fn diffs_answers_by_line() {
+	assert_eq!(
+		watch::diff("#..\n.#.\n..#", "#..\n###\n..#"),
+		vec![
+			Change::Same("#.."),
+			Change::Removed(".#."),
+			Change::Added("###"),
+			Change::Same("..#"),
+		]
+	);
+}
+
+#[test]
+fn wakes_up_when_a_file_is_replaced() {
+	let dir = common::temp_dir("watch");
+	let path = dir.join("day_one");
+	fs::write(&path, "1\n").unwrap();
+	let mut watcher = Watcher::new(std::slice::from_ref(&path)).unwrap();
+
+	let writer = thread::spawn(move || {
+		thread::sleep(Duration::from_millis(50));
+		fs::write(dir.join("unrelated"), "2\n").unwrap();
+		fs::write(dir.join("day_one.tmp"), "3\n").unwrap();
+		fs::rename(dir.join("day_one.tmp"), path).unwrap();
+	});
+	watcher.wait().unwrap();
+	writer.join().unwrap();
+}
@@ -0,0 +1,1136 @@
+use std::{
+	cmp::Reverse,
+	env, fs,
+	io::{self, IsTerminal},
+	num::NonZeroUsize,
+	panic::{self, AssertUnwindSafe},
+	path::{Path, PathBuf},
+	str::FromStr,
+	time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+#[cfg(target_os = "linux")]
+use aoc::watch::{self, Watcher};
+use aoc::{
+	allocs::{self, Counting},
+	answers::Answers,
+	bench::{self, Report, Stats},
+	client::Client,
+	day::{Day, DEFAULT_IMPL},
+	diffcheck, examples,
+	input::{self, InputSource},
+	isolate::{self, Limits},
+	leaderboard::{self, Leaderboard},
+	output::{self, Entry, Format},
+	panic_message, parallel, plugin,
+	progress::{self, Progress},
+	puzzle::{self, Puzzle},
+	scaffold,
+	shrink::{self, Predicate},
+	submit::{self, Outcome},
+	trace, Year,
+};
+use aoc_years::YEARS;
+use clap::{Args, Parser, Subcommand};
+
+// Only counts once `--alloc-stats` enables it
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+	/// Year of the puzzles [default: the latest one]
+	#[arg(long, global = true)]
+	year: Option<u16>,
+	#[command(flatten)]
+	input: InputArgs,
+	/// Print spans with their timings to stderr: -v for days, -vv for parsing and parts, -vvv for
+	/// the iterations of hot loops
+	#[arg(short, long, global = true, action = clap::ArgAction::Count)]
+	verbose: u8,
+	/// Write the time spent in every span as folded stacks, for flamegraph tools
+	#[arg(long, global = true, value_name = "FILE")]
+	profile: Option<PathBuf>,
+	/// Add the days solved by the plugins in this directory, built with `aoc::export_plugin!`
+	/// [env: AOC_PLUGIN_DIR]
+	#[arg(long, global = true, value_name = "DIR")]
+	plugins: Option<PathBuf>,
+}
+
+// Every year with the days of the plugins in `dir` added
+fn years(dir: Option<&Path>) -> Result<Vec<&'static Year>> {
+	let dir = dir
+		.map(Path::to_path_buf)
+		.or_else(|| env::var_os(plugin::PLUGIN_DIR_VAR).map(PathBuf::from));
+	match dir {
+		Some(dir) => plugin::register(YEARS, plugin::load_dir(&dir)?),
+		None => Ok(YEARS.to_vec()),
+	}
+}
+
+fn year(years: &[&'static Year], year: Option<u16>) -> Result<&'static Year> {
+	match year {
+		None => Ok(years[years.len() - 1]),
+		Some(year) => years
+			.iter()
+			.copied()
+			.find(|y| y.year == year)
+			.ok_or_else(|| {
+				anyhow!(
+					"there are no solutions for {year}, only for {}",
+					years
+						.iter()
+						.map(|y| y.year.to_string())
+						.collect::<Vec<_>>()
+						.join(", ")
+				)
+			}),
+	}
+}
+
+#[derive(Args)]
+struct InputArgs {
+	/// Directory to read the `day_*` inputs from [env: AOC_INPUT_DIR]
+	#[arg(long, global = true)]
+	input_dir: Option<PathBuf>,
+	/// Read the input of a single day from this file
+	#[arg(long, global = true, conflicts_with = "stdin")]
+	input: Option<PathBuf>,
+	/// Read the input of a single day from stdin
+	#[arg(long, global = true)]
+	stdin: bool,
+}
+
+impl InputArgs {
+	fn source(&self, year: &'static Year) -> InputSource {
+		if let Some(path) = &self.input {
+			InputSource::File(path.clone())
+		} else if self.stdin {
+			InputSource::Stdin
+		} else {
+			self.dir(year)
+		}
+	}
+
+	// Commands that key answers by input name only read from an input directory
+	fn dir(&self, year: &'static Year) -> InputSource {
+		match &self.input_dir {
+			Some(dir) => InputSource::Dir(dir.clone()),
+			None => InputSource::Year(year),
+		}
+	}
+
+	fn dir_path(&self, year: &Year) -> PathBuf {
+		self.input_dir.clone().unwrap_or_else(|| year.input_dir())
+	}
+}
+
+#[derive(Args)]
+struct LimitArgs {
+	/// Run every day as its `day_*` binary in a child process, as built by
+	/// `cargo build --workspace --bins`
+	#[arg(long)]
+	isolate: bool,
+	/// Kill an isolated day after this many seconds [default: from aoc.toml]
+	#[arg(long, value_name = "SECS", requires = "isolate")]
+	timeout: Option<f64>,
+	/// Limit the address space of an isolated day to this many MiB [default: from aoc.toml]
+	#[arg(long, value_name = "MIB", requires = "isolate")]
+	memory: Option<u64>,
+}
+
+impl LimitArgs {
+	// `None` runs days in this process
+	fn limits(&self, year: &Year) -> Result<Option<Limits>> {
+		if !self.isolate {
+			return Ok(None);
+		}
+		let mut limits = year.config()?.limits.limits()?;
+		if let Some(timeout) = self.timeout {
+			limits.timeout = Some(isolate::timeout(timeout)?);
+		}
+		if let Some(memory) = self.memory {
+			limits.memory = Some(memory << 20);
+		}
+		Ok(Some(limits))
+	}
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Run one or all days and print their answers
+	Run {
+		/// Day number or `all`
+		day: DaySelection,
+		/// Only solve this part
+		#[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+		part: Option<u8>,
+		/// Solve example N (default from aoc.toml) from input/examples instead of the puzzle input
+		#[arg(long, num_args = 0..=1, value_name = "N", conflicts_with_all = ["input", "stdin"])]
+		example: Option<Option<u8>>,
+		/// How to print the answers
+		#[arg(long, value_enum, default_value_t)]
+		format: Format,
+		#[command(flatten)]
+		limits: LimitArgs,
+		/// Count the allocations, bytes and peak live bytes of parsing and each part
+		#[arg(long, conflicts_with = "isolate")]
+		alloc_stats: bool,
+		/// Solve up to this many days at once, answers are still printed in order
+		#[arg(short, long, value_name = "N", default_value = "1")]
+		jobs: NonZeroUsize,
+		/// Solve the parts with the implementation of this name, with `all` only the days that have
+		/// one
+		#[arg(long = "impl", value_name = "NAME", default_value = DEFAULT_IMPL)]
+		implementation: String,
+	},
+	/// Time parsing and both parts of one or all days
+	Bench {
+		/// Day number or `all`
+		day: DaySelection,
+		/// Timed runs of every step [default: from aoc.toml, or 20]
+		#[arg(long)]
+		iterations: Option<usize>,
+		/// Untimed runs of every step before measuring [default: from aoc.toml, or 3]
+		#[arg(long)]
+		warmup: Option<usize>,
+		/// Where to write the JSON report
+		#[arg(long, default_value = "bench.json")]
+		report: PathBuf,
+	},
+	/// Record the current answers of one or all days as accepted
+	Accept {
+		/// Day number or `all`
+		day: DaySelection,
+		/// Only accept the answer of this part
+		#[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+		part: Option<u8>,
+		/// Accept the answers of example N (default from aoc.toml) as its expected answers
+		#[arg(long, num_args = 0..=1, value_name = "N")]
+		example: Option<Option<u8>>,
+	},
+	/// Re-run every day and compare its answers with the accepted ones
+	Verify,
+	/// Solve the input and examples of days with all of their implementations and flag any
+	/// disagreement
+	Diffcheck {
+		/// Day number or `all`
+		day: DaySelection,
+	},
+	/// Remove lines and tokens from the input of a day while it still fails the same way, and write
+	/// what's left
+	Shrink {
+		/// Day number
+		day: u8,
+		/// How the day has to keep failing
+		#[arg(long, value_enum, default_value = "impls-differ")]
+		predicate: Predicate,
+		/// Shrink example N (default from aoc.toml) from input/examples instead of the puzzle input
+		#[arg(long, num_args = 0..=1, value_name = "N", conflicts_with_all = ["input", "stdin"])]
+		example: Option<Option<u8>>,
+		/// Where to write the shrunk input [default: day_<name>.min]
+		#[arg(short, long, value_name = "FILE")]
+		output: Option<PathBuf>,
+	},
+	/// Show which days are solved, have inputs and verified answers as a calendar
+	Status {
+		/// Print the status of every day as JSON instead
+		#[arg(long)]
+		json: bool,
+	},
+	/// Submit the answer of a part and record whether it was accepted
+	Submit {
+		/// Day number
+		day: u8,
+		/// Part to submit
+		#[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+		part: u8,
+	},
+	/// Create the solution, input and example files of a new day
+	New {
+		/// Day number
+		#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+		day: u8,
+	},
+	/// Download the input of one or all days, unless it's already in input/
+	Fetch {
+		/// Day number or `all`
+		day: DaySelection,
+	},
+	/// Save the puzzle description of a day as Markdown in puzzles/, with its candidate examples
+	Read {
+		/// Day number
+		#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+		day: u8,
+		/// Read a saved puzzle page instead of downloading it
+		#[arg(long, value_name = "FILE")]
+		html: Option<PathBuf>,
+	},
+	/// Show the standings of a private leaderboard, or the star times of a single day
+	Leaderboard {
+		/// Leaderboard id, the number at the end of its URL (default from aoc.toml)
+		id: Option<u64>,
+		/// Show the star times and points of this day instead
+		#[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+		day: Option<u8>,
+		/// Read a saved leaderboard JSON export instead of downloading it
+		#[arg(long, value_name = "FILE")]
+		json: Option<PathBuf>,
+	},
+	/// Re-run a day whenever its input changes and show how its answers changed
+	#[cfg(target_os = "linux")]
+	Watch {
+		/// Day number
+		day: u8,
+		/// Also watch and solve example N (default from aoc.toml) from input/examples
+		#[arg(long, num_args = 0..=1, value_name = "N")]
+		example: Option<Option<u8>>,
+	},
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+	All,
+	Day(u8),
+}
+
+impl FromStr for DaySelection {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		if s == "all" {
+			return Ok(Self::All);
+		}
+		match s.parse::<u8>() {
+			Ok(day) if (1..=25).contains(&day) => Ok(Self::Day(day)),
+			_ => Err(anyhow!(
+				"expected a day between 1 and 25 or `all`, got '{s}'"
+			)),
+		}
+	}
+}
+
+impl DaySelection {
+	fn days(self, year: &Year) -> Result<Vec<&'static Day>> {
+		match self {
+			Self::All => Ok(year.days.iter().collect()),
+			Self::Day(day) => Ok(vec![year.day(day)?]),
+		}
+	}
+
+	fn days_reading(self, year: &Year, source: &InputSource) -> Result<Vec<&'static Day>> {
+		let days = self.days(year)?;
+		if days.len() > 1 && !source.is_per_day() {
+			bail!("--input and --stdin can only be used with a single day");
+		}
+		Ok(days)
+	}
+
+	// Unlike `days`, a single day doesn't need to be solved yet
+	fn numbers(self, year: &Year) -> Vec<u8> {
+		match self {
+			Self::All => year.days.iter().map(|d| d.day).collect(),
+			Self::Day(day) => vec![day],
+		}
+	}
+}
+
+fn main() -> Result<()> {
+	let cli = Cli::parse();
+	let folded = trace::init(cli.verbose, cli.profile.is_some());
+	let profile = cli.profile.clone();
+	let result = execute(cli);
+	// Also profile runs that failed, the stacks up to the failure are still useful
+	if let (Some(folded), Some(path)) = (folded, profile) {
+		folded.write(&path)?;
+	}
+	result
+}
+
+fn execute(cli: Cli) -> Result<()> {
+	let year = year(&years(cli.plugins.as_deref())?, cli.year)?;
+	let config = year.config()?;
+	let input = &cli.input;
+	let example = |example: Option<Option<u8>>| example.map(|n| n.unwrap_or(config.example));
+	match cli.command {
+		Command::Run {
+			day,
+			part,
+			example: ex,
+			format,
+			limits,
+			alloc_stats,
+			jobs,
+			implementation,
+		} => run(
+			year,
+			day,
+			&example_or(year, day, example(ex), input.source(year))?,
+			RunOptions {
+				part,
+				format,
+				limits: limits.limits(year)?,
+				alloc_stats,
+				jobs,
+				implementation,
+			},
+		),
+		Command::Bench {
+			day,
+			iterations,
+			warmup,
+			report,
+		} => bench(
+			year,
+			day,
+			iterations.unwrap_or(config.bench.iterations),
+			warmup.unwrap_or(config.bench.warmup),
+			report,
+			&input.source(year),
+		),
+		Command::Accept {
+			day,
+			part,
+			example: ex,
+		} => accept(year, day, part, example(ex), &input.dir(year)),
+		Command::Verify => verify(year, &input.dir(year)),
+		Command::Diffcheck { day } => diffcheck(year, day, &input.dir(year)),
+		Command::Shrink {
+			day,
+			predicate,
+			example: ex,
+			output,
+		} => shrink(
+			year,
+			day,
+			predicate,
+			&example_or(
+				year,
+				DaySelection::Day(day),
+				example(ex),
+				input.source(year),
+			)?,
+			output,
+		),
+		Command::Status { json } => status(year, json),
+		Command::Submit { day, part } => submit(year, day, part, &input.dir(year)),
+		Command::New { day } => new(year, day),
+		Command::Fetch { day } => fetch(year, day, &input.dir_path(year)),
+		Command::Read { day, html } => read(year, day, html.as_deref()),
+		Command::Leaderboard { id, day, json } => leaderboard(year, id, day, json.as_deref()),
+		#[cfg(target_os = "linux")]
+		Command::Watch { day, example: ex } => watch(year, day, example(ex), input),
+	}
+}
+
+// Examples are per day, so they can only replace the input of a single one
+fn example_or(
+	year: &Year,
+	selection: DaySelection,
+	example: Option<u8>,
+	source: InputSource,
+) -> Result<InputSource> {
+	let Some(example) = example else {
+		return Ok(source);
+	};
+	match selection.days(year)?.as_slice() {
+		[day] => Ok(InputSource::Example(
+			example,
+			examples::path(year, day.name, example),
+		)),
+		_ => bail!("--example can only be used with a single day"),
+	}
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+	match part {
+		Some(part) => vec![part],
+		None => vec![1, 2],
+	}
+}
+
+// How `aoc run` solves and reports the selected days
+struct RunOptions {
+	part: Option<u8>,
+	format: Format,
+	limits: Option<Limits>,
+	alloc_stats: bool,
+	jobs: NonZeroUsize,
+	implementation: String,
+}
+
+// How many of the slowest days the report of a run lists
+const SLOWEST_DAYS: usize = 3;
+
+fn run(
+	year: &Year,
+	selection: DaySelection,
+	source: &InputSource,
+	options: RunOptions,
+) -> Result<()> {
+	if options.alloc_stats {
+		if options.jobs.get() > 1 {
+			bail!("--alloc-stats counts allocations of the whole process, so it needs --jobs 1");
+		}
+		allocs::enable()?;
+	}
+	let parts = parts(options.part);
+	let days = selection.days_reading(year, source)?;
+	let start = Instant::now();
+	let solved = parallel::map(&days, options.jobs, |day| {
+		let start = Instant::now();
+		let implementation = match selection {
+			DaySelection::All if !day.has_implementation(&options.implementation) => DEFAULT_IMPL,
+			_ => &options.implementation,
+		};
+		let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+			solve_day(
+				year,
+				day,
+				source,
+				implementation,
+				options.part,
+				options.limits.as_ref(),
+			)
+		}))
+		.unwrap_or_else(|panic| bail!("panicked: {}", panic_message(&*panic)));
+		(solved, start.elapsed())
+	});
+	let wall = start.elapsed();
+
+	let mut rows = Vec::new();
+	let mut entries = Vec::new();
+	let mut failures = Vec::new();
+	for (day, (solved, _)) in days.iter().zip(&solved) {
+		match solved {
+			Ok(solved) => {
+				for entry in solved {
+					rows.push((entry.day, entry.part, entry.answer.clone()));
+					entries.push(entry.clone());
+				}
+			}
+			Err(e) => {
+				failures.push((day.day, format!("{e:#}")));
+				rows.push((day.day, parts[0], format!("error: {e:#}")));
+			}
+		}
+	}
+	match options.format {
+		Format::Text => {
+			print_summary("Answer", &rows);
+			if options.alloc_stats {
+				print_allocs(&entries);
+			}
+		}
+		Format::Json => println!("{}", output::json(&entries)?),
+		Format::Csv => print!("{}", output::csv(&entries)),
+	}
+	if days.len() > 1 {
+		let times = days
+			.iter()
+			.zip(&solved)
+			.map(|(day, (_, time))| (day.day, *time));
+		print_report(options.format, wall, options.jobs, times, &failures);
+	} else if options.format != Format::Text {
+		// Keep structured output parseable, failures still show up in the exit status
+		for (day, error) in &failures {
+			eprintln!("day {day}: {error}");
+		}
+	}
+	if source.is_per_day() {
+		record(year, &entries)?;
+	}
+	if !failures.is_empty() {
+		bail!("{} day(s) failed", failures.len());
+	}
+	Ok(())
+}
+
+// Wall time of a run of several days, its slowest days and why days failed. Goes to stderr with
+// structured output so it stays parseable.
+fn print_report(
+	format: Format,
+	wall: Duration,
+	jobs: NonZeroUsize,
+	times: impl Iterator<Item = (u8, Duration)>,
+	failures: &[(u8, String)],
+) {
+	let mut times = times.collect::<Vec<_>>();
+	let mut report = format!(
+		"\nSolved {} of {} days in {wall:.2?} on {jobs} thread(s)\n",
+		times.len() - failures.len(),
+		times.len()
+	);
+	// Ties are broken by day, so the report doesn't depend on scheduling
+	times.sort_by_key(|(day, time)| (Reverse(*time), *day));
+	report.push_str(&format!(
+		"Slowest: {}\n",
+		times
+			.iter()
+			.take(SLOWEST_DAYS)
+			.map(|(day, time)| format!("day {day} ({time:.2?})"))
+			.collect::<Vec<_>>()
+			.join(", ")
+	));
+	for (day, error) in failures {
+		report.push_str(&format!("Failed: day {day}: {error}\n"));
+	}
+	if format == Format::Text {
+		print!("{report}");
+	} else {
+		eprint!("{report}");
+	}
+}
+
+// Solves both parts of a day, or only `part`, in this process, or in a child process within
+// `limits` if given
+fn solve_day(
+	year: &Year,
+	day: &Day,
+	source: &InputSource,
+	implementation: &str,
+	part: Option<u8>,
+	limits: Option<&Limits>,
+) -> Result<Vec<Entry>> {
+	let input = source.load(day.name)?;
+	let Some(limits) = limits else {
+		return output::solve_parts(day, &input, implementation, &parts(part));
+	};
+	// Fail before starting a process that can only fail
+	drop(day.solvers(implementation)?);
+	let binary = isolate::binary(year, day.name)?;
+	match isolate::run(&binary, &input, implementation, part, limits)? {
+		isolate::Outcome::Solved(entries) => Ok(entries),
+		outcome => bail!("{outcome}"),
+	}
+}
+
+fn run_day(day: &Day, source: &InputSource) -> Result<[String; 2]> {
+	day.solve(&source.load(day.name)?)
+}
+
+fn answers_path(year: &Year) -> Result<PathBuf> {
+	Ok(year.config()?.answers_path())
+}
+
+fn accept(
+	year: &Year,
+	selection: DaySelection,
+	part: Option<u8>,
+	example: Option<u8>,
+	source: &InputSource,
+) -> Result<()> {
+	let source = example_or(year, selection, example, source.clone())?;
+	let mut answers = Answers::load(answers_path(year)?)?;
+	let mut rows = Vec::new();
+	for day in selection.days(year)? {
+		let solved = run_day(day, &source)?;
+		let input = match example {
+			Some(example) => examples::input_name(day.name, example),
+			None => day.input_name(),
+		};
+		for part in parts(part) {
+			let answer = solved[part as usize - 1].clone();
+			answers.set(day.day, part, &input, answer.clone());
+			rows.push((day.day, part, answer));
+		}
+	}
+	answers.save(answers_path(year)?)?;
+	print_summary("Accepted", &rows);
+	Ok(())
+}
+
+fn verify(year: &Year, source: &InputSource) -> Result<()> {
+	let answers = Answers::load(answers_path(year)?)?;
+	let mut rows = Vec::new();
+	let mut entries = Vec::new();
+	let mut mismatches = 0;
+	for day in year.days {
+		let solved = match source
+			.load(day.name)
+			.and_then(|input| output::solve_timed(day, &input))
+		{
+			Ok(solved) => solved,
+			Err(e) => {
+				mismatches += 1;
+				rows.push((day.day, 1, format!("error: {e:#}")));
+				continue;
+			}
+		};
+		for entry in solved {
+			let status = match answers.get(day.day, entry.part, &day.input_name()) {
+				Some(expected) if expected == entry.answer => String::from("ok"),
+				Some(expected) => {
+					mismatches += 1;
+					format!("mismatch\nexpected:\n{expected}\ngot:\n{}", entry.answer)
+				}
+				None => String::from("unrecorded"),
+			};
+			rows.push((day.day, entry.part, status));
+			entries.push(entry);
+		}
+	}
+	print_summary("Status", &rows);
+	record(year, &entries)?;
+	if mismatches > 0 {
+		bail!(
+			"{mismatches} answer(s) don't match {}",
+			answers_path(year)?.display()
+		);
+	}
+	Ok(())
+}
+
+fn diffcheck(year: &Year, selection: DaySelection, source: &InputSource) -> Result<()> {
+	let days = selection
+		.days(year)?
+		.into_iter()
+		.filter(|day| diffcheck::has_alternatives(day))
+		.collect::<Vec<_>>();
+	if days.is_empty() {
+		bail!("no selected day has alternative implementations, see Solution::implementations");
+	}
+	let mut rows = Vec::new();
+	let mut disagreements = 0;
+	for day in days {
+		let mut inputs = examples::list(year, day.name)
+			.into_iter()
+			.map(|n| {
+				let path = examples::path(year, day.name, n);
+				Ok((examples::input_name(day.name, n), fs::read_to_string(path)?))
+			})
+			.collect::<Result<Vec<_>>>()?;
+		// Without the real input the examples are still worth comparing
+		match source.load(day.name) {
+			Ok(input) => inputs.insert(0, (day.input_name(), input)),
+			Err(e) => eprintln!("day {}: skipping the puzzle input: {e:#}", day.day),
+		}
+		for (name, input) in inputs {
+			for comparison in diffcheck::compare(day, &input)? {
+				let agrees = comparison.agrees();
+				if !agrees {
+					disagreements += 1;
+				}
+				for (implementation, answer) in comparison.answers {
+					let answer = answer.unwrap_or_else(|e| format!("error: {e}"));
+					let status = if agrees { "agrees" } else { "DIFFERS" };
+					rows.push((
+						day.day,
+						comparison.part,
+						name.clone(),
+						implementation,
+						answer,
+						status,
+					));
+				}
+			}
+		}
+	}
+
+	let input_width = rows
+		.iter()
+		.map(|r| r.2.len())
+		.max()
+		.unwrap_or(0)
+		.max("Input".len());
+	let impl_width = rows
+		.iter()
+		.map(|r| r.3.len())
+		.max()
+		.unwrap_or(0)
+		.max("Implementation".len());
+	println!(
+		"Day | Part | {:<input_width$} | {:<impl_width$} | Status  | Answer",
+		"Input", "Implementation"
+	);
+	println!(
+		"----+------+-{}-+-{}-+---------+-------",
+		"-".repeat(input_width),
+		"-".repeat(impl_width)
+	);
+	for (day, part, input, implementation, answer, status) in &rows {
+		for (i, line) in answer.lines().enumerate() {
+			if i == 0 {
+				println!(
+					"{day:>3} | {part:>4} | {input:<input_width$} | {implementation:<impl_width$} | {status:<7} | {line}"
+				);
+			} else {
+				println!(
+					"    |      | {:input_width$} | {:impl_width$} |         | {line}",
+					"", ""
+				);
+			}
+		}
+	}
+	if disagreements > 0 {
+		bail!("implementations disagree on {disagreements} part(s)");
+	}
+	Ok(())
+}
+
+fn shrink(
+	year: &Year,
+	day: u8,
+	predicate: Predicate,
+	source: &InputSource,
+	output: Option<PathBuf>,
+) -> Result<()> {
+	let day = year.day(day)?;
+	let input = source.load(day.name)?;
+	let shrunk = shrink::shrink(day, &input, predicate)?;
+	let output = output.unwrap_or_else(|| PathBuf::from(format!("day_{}.min", day.name)));
+	fs::write(&output, &shrunk.input)
+		.with_context(|| format!("couldn't write {}", output.display()))?;
+	println!(
+		"Shrunk day {} from {} to {} lines ({} to {} bytes) after {} tests",
+		day.day,
+		input.lines().count(),
+		shrunk.input.lines().count(),
+		input.len(),
+		shrunk.input.len(),
+		shrunk.tests
+	);
+	println!("Failure: {}", shrunk.failure);
+	println!("Wrote {}", output.display());
+	Ok(())
+}
+
+// Keeps the progress file shown by `aoc status` up to date with runs on the real inputs, leaving
+// it alone when nothing was solved
+fn record(year: &Year, entries: &[Entry]) -> Result<()> {
+	if entries.is_empty() {
+		return Ok(());
+	}
+	let config = year.config()?;
+	let answers = Answers::load(config.answers_path())?;
+	let mut progress = Progress::load(config.progress_path())?;
+	for entry in entries {
+		let input = year.day(entry.day)?.input_name();
+		progress.record(entry, answers.get(entry.day, entry.part, &input));
+	}
+	progress.save(config.progress_path())
+}
+
+fn status(year: &'static Year, json: bool) -> Result<()> {
+	let days = progress::status(year, &Progress::load(year.config()?.progress_path())?);
+	if json {
+		println!("{}", serde_json::to_string_pretty(&days)?);
+		return Ok(());
+	}
+
+	println!("Advent of Code {}\n", year.year);
+	print!("{}", progress::calendar(year.year, &days));
+	println!("\n** both parts verified, * one part verified, .. solved but not verified\n");
+	println!(
+		"Day | Input | Part | {:<10} | {:>10} | {:>10}",
+		"Status", "Parse", "Solve"
+	);
+	println!(
+		"----+-------+------+-{}",
+		vec!["-".repeat(10); 3].join("-+-")
+	);
+	for day in days.iter().filter(|d| d.solved || d.input) {
+		let input = if day.input { "yes" } else { "no" };
+		if day.parts.is_empty() {
+			println!("{:>3} | {input:<5} |    - | {:<10} |", day.day, "not run");
+		}
+		for part in &day.parts {
+			let answer = match &part.expected {
+				_ if part.is_verified() => "verified",
+				Some(_) => "wrong",
+				None => "unverified",
+			};
+			println!(
+				"{:>3} | {input:<5} | {:>4} | {answer:<10} | {:>10} | {:>10}",
+				day.day,
+				part.part,
+				format!("{:.2?}", Duration::from_nanos(part.parse_ns)),
+				format!("{:.2?}", Duration::from_nanos(part.solve_ns)),
+			);
+		}
+	}
+	let stars = days.iter().map(|d| d.stars()).sum::<usize>();
+	println!("\n{stars}/50 stars");
+	Ok(())
+}
+
+fn submit(year: &Year, day: u8, part: u8, source: &InputSource) -> Result<()> {
+	let day = year.day(day)?;
+	let input = day.input_name();
+	let answer = run_day(day, source)?[part as usize - 1].clone();
+	let mut answers = Answers::load(answers_path(year)?)?;
+	if answers.get(day.day, part, &input) == Some(answer.as_str()) {
+		println!("Day {} part {part}: {answer} was already accepted", day.day);
+		return Ok(());
+	}
+	if let Some(rejected) = answers.rejection(day.day, part, &input, &answer) {
+		bail!(
+			"{answer} was already submitted for day {} part {part} and was {}",
+			day.day,
+			rejected.outcome
+		);
+	}
+
+	let outcome = submit::submit(&Client::from_env(year.config()?)?, day.day, part, &answer)?;
+	if outcome == Outcome::Correct {
+		answers.set(day.day, part, &input, answer.clone());
+	} else if outcome.is_rejection() {
+		answers.reject(day.day, part, &input, answer.clone(), outcome);
+	}
+	answers.save(answers_path(year)?)?;
+	println!("Day {} part {part}: {answer} is {outcome}", day.day);
+	Ok(())
+}
+
+fn new(year: &Year, day: u8) -> Result<()> {
+	for path in scaffold::new_day(year, day)? {
+		println!("Created {}", path.display());
+	}
+	Ok(())
+}
+
+fn fetch(year: &Year, selection: DaySelection, dir: &Path) -> Result<()> {
+	let client = Client::from_env(year.config()?)?;
+	for day in selection.numbers(year) {
+		let path = dir.join(format!("day_{}", aoc::day_name(day).unwrap()));
+		if input::is_saved(&path) {
+			println!("Day {day}: already cached in {}", path.display());
+		} else {
+			client.cached_input(day, &path)?;
+			println!("Day {day}: downloaded to {}", path.display());
+		}
+	}
+	Ok(())
+}
+
+fn read(year: &Year, day: u8, html: Option<&Path>) -> Result<()> {
+	let puzzle = match html {
+		Some(path) => Puzzle::parse(
+			&fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))?,
+		)?,
+		None => puzzle::fetch(&Client::from_env(year.config()?)?, day)?,
+	};
+	for path in puzzle::save(year, day, &puzzle)? {
+		println!("Wrote {}", path.display());
+	}
+	for (part, answers) in puzzle.answers.iter().enumerate() {
+		if let Some(answer) = answers.last() {
+			println!(
+				"Part {}: likely example answer {answer} (of {})",
+				part + 1,
+				answers.join(", ")
+			);
+		}
+	}
+	Ok(())
+}
+
+fn leaderboard(year: &Year, id: Option<u64>, day: Option<u8>, json: Option<&Path>) -> Result<()> {
+	let config = year.config()?;
+	let board = match json {
+		Some(path) => Leaderboard::parse(
+			&fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))?,
+		)?,
+		None => {
+			let id = id.or(config.leaderboard).ok_or_else(|| {
+				anyhow!("no leaderboard given, pass its id or set `leaderboard` in aoc.toml")
+			})?;
+			leaderboard::fetch(&Client::from_env(config)?, id)?
+		}
+	};
+	println!("Advent of Code {} private leaderboard\n", board.event);
+	match day {
+		Some(day) => print!("{}", board.day_table(day)?),
+		None => print!("{}", board.standings()),
+	}
+	Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn watch(year: &'static Year, day: u8, example: Option<u8>, input: &InputArgs) -> Result<()> {
+	let day = year.day(day)?;
+	let mut targets = vec![input
+		.source(year)
+		.path(day.name)
+		.ok_or_else(|| anyhow!("--stdin can't be watched, use --input instead"))?];
+	if let Some(example) = example {
+		targets.push(examples::path(year, day.name, example));
+	}
+	let mut watcher = Watcher::new(&targets)?;
+	let mut previous = vec![None; targets.len()];
+	loop {
+		for (path, previous) in targets.iter().zip(&mut previous) {
+			println!("Day {} with {}", day.day, path.display());
+			match run_day(day, &InputSource::File(path.clone())) {
+				Ok(answers) => {
+					print_changes(previous.as_ref(), &answers);
+					*previous = Some(answers);
+				}
+				Err(e) => println!("{}", paint(&format!("error: {e:#}"), RED)),
+			}
+		}
+		println!("\nWatching for changes, press Ctrl-C to stop\n");
+		watcher.wait()?;
+	}
+}
+
+#[cfg(target_os = "linux")]
+fn print_changes(previous: Option<&[String; 2]>, answers: &[String; 2]) {
+	use aoc::watch::Change;
+
+	for (i, (part, answer)) in ["one", "two"].iter().zip(answers).enumerate() {
+		let Some(previous) = previous.map(|p| &p[i]).filter(|p| *p != answer) else {
+			if answer.contains('\n') {
+				println!("Part {part}: \u{2193}\n{answer}");
+			} else {
+				println!("Part {part}: {answer}");
+			}
+			continue;
+		};
+		if !answer.contains('\n') && !previous.contains('\n') {
+			println!(
+				"Part {part}: {}",
+				paint(&format!("{previous} -> {answer}"), YELLOW)
+			);
+			continue;
+		}
+		println!("Part {part}: {}", paint("changed", YELLOW));
+		for change in watch::diff(previous, answer) {
+			match change {
+				Change::Same(line) => println!("  {line}"),
+				Change::Removed(line) => println!("{}", paint(&format!("- {line}"), RED)),
+				Change::Added(line) => println!("{}", paint(&format!("+ {line}"), GREEN)),
+			}
+		}
+	}
+}
+
+#[cfg(target_os = "linux")]
+const RED: &str = "31";
+#[cfg(target_os = "linux")]
+const GREEN: &str = "32";
+#[cfg(target_os = "linux")]
+const YELLOW: &str = "1;33";
+
+#[cfg(target_os = "linux")]
+// Colours text with an ANSI escape code, unless the output isn't a terminal
+fn paint(text: &str, code: &str) -> String {
+	if io::stdout().is_terminal() {
+		format!("\x1b[{code}m{text}\x1b[0m")
+	} else {
+		text.to_string()
+	}
+}
+
+fn print_summary(header: &str, rows: &[(u8, u8, String)]) {
+	let width = rows
+		.iter()
+		.flat_map(|(_, _, answer)| answer.lines())
+		.map(|l| l.chars().count())
+		.max()
+		.unwrap_or(0)
+		.max(header.len());
+	println!("Day | Part | {header}");
+	println!("----+------+-{}", "-".repeat(width));
+	for (day, part, answer) in rows {
+		for (i, line) in answer.lines().enumerate() {
+			if i == 0 {
+				println!("{day:>3} | {part:>4} | {line}");
+			} else {
+				println!("    |      | {line}");
+			}
+		}
+	}
+}
+
+// Parsing is shared by both parts, so it's only shown once per day
+fn print_allocs(entries: &[Entry]) {
+	println!(
+		"\nDay | Phase    | {:>11} | {:>11} | {:>11}",
+		"Allocations", "Bytes", "Peak"
+	);
+	println!("----+----------+-{}", vec!["-".repeat(11); 3].join("-+-"));
+	let mut previous = None;
+	for entry in entries {
+		let mut phases = vec![];
+		if previous != Some(entry.day) {
+			phases.push(("parse", entry.parse_alloc));
+		}
+		phases.push((
+			if entry.part == 1 {
+				"part one"
+			} else {
+				"part two"
+			},
+			entry.solve_alloc,
+		));
+		for (phase, stats) in phases {
+			let stats = stats.unwrap_or_default();
+			println!(
+				"{:>3} | {phase:<8} | {:>11} | {:>11} | {:>11}",
+				entry.day,
+				stats.allocations,
+				bytes(stats.bytes),
+				bytes(stats.peak_bytes)
+			);
+		}
+		previous = Some(entry.day);
+	}
+}
+
+fn bytes(bytes: u64) -> String {
+	match bytes {
+		0..=1023 => format!("{bytes} B"),
+		1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+		_ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+	}
+}
+
+fn bench(
+	year: &Year,
+	selection: DaySelection,
+	iterations: usize,
+	warmup: usize,
+	path: PathBuf,
+	source: &InputSource,
+) -> Result<()> {
+	let mut report = Report {
+		iterations,
+		warmup,
+		days: Vec::new(),
+	};
+	println!(
+		"Day | Step     | {:>10} | {:>10} | {:>10} | {:>10}",
+		"Min", "Median", "Mean", "Stddev"
+	);
+	println!("----+----------+{}", ["------------"; 4].join("+"));
+	for day in selection.days_reading(year, source)? {
+		let result = bench::bench_day(day, &source.load(day.name)?, iterations, warmup)?;
+		for (step, stats) in [
+			("parse", result.parse),
+			("part one", result.part_one),
+			("part two", result.part_two),
+		] {
+			print_stats(day.day, step, &stats);
+		}
+		report.days.push(result);
+	}
+	fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+	println!("\nReport written to {}", path.display());
+	Ok(())
+}
+
+fn print_stats(day: u8, step: &str, stats: &Stats) {
+	let fmt = |d: Duration| format!("{d:.2?}");
+	println!(
+		"{day:>3} | {step:<8} | {:>10} | {:>10} | {:>10} | {:>10}",
+		fmt(stats.min),
+		fmt(stats.median),
+		fmt(stats.mean),
+		fmt(stats.stddev)
+	);
+}
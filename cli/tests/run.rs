@@ -0,0 +1,50 @@
+use std::{
+	env, fs,
+	path::Path,
+	process::{Command, Output},
+};
+
+// Runs the aoc binary from the workspace root, reading inputs from `input_dir` if given
+fn aoc(args: &[&str], input_dir: Option<&Path>) -> Output {
+	let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+	command
+		.args(args)
+		.current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+		.env_remove("AOC_INPUT_DIR")
+		.env_remove("AOC_CONFIG")
+		.env_remove("AOC_PLUGIN_DIR")
+		.env_remove("RUST_BACKTRACE");
+	if let Some(dir) = input_dir {
+		command.env("AOC_INPUT_DIR", dir);
+	}
+	command.output().unwrap()
+}
+
+// Puzzle inputs of 2021 aren't in the repository, only its examples
+#[test]
+fn points_at_aoc_fetch_for_missing_inputs() {
+	// Without any inputs, in case they were downloaded
+	let inputs = env::temp_dir().join(format!("aoc-run-inputs-{}", std::process::id()));
+	fs::create_dir_all(&inputs).unwrap();
+	let output = aoc(&["--year", "2021", "run", "5"], Some(&inputs));
+	fs::remove_dir_all(inputs).unwrap();
+	assert!(!output.status.success());
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(
+		stdout.contains("download it with `aoc fetch --year 2021 5`"),
+		"{stdout}"
+	);
+}
+
+#[test]
+fn solves_examples_from_the_root() {
+	let output = aoc(&["--year", "2021", "run", "5", "--example"], None);
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert!(stdout.contains("  5 |    1 | 5\n"), "{stdout}");
+	assert!(stdout.contains("  5 |    2 | 12\n"), "{stdout}");
+}
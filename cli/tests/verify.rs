@@ -0,0 +1,57 @@
+use std::{
+	env, fs,
+	path::Path,
+	process::{Command, Output},
+};
+
+use aoc::answers::Answers;
+
+// Runs `aoc verify` for 2021, which only solves day 5, with its example as the real input and
+// `answers` recorded for it. Everything lives in a temporary directory named by AOC_CONFIG.
+fn verify(name: &str, answers: [&str; 2]) -> Output {
+	let dir = env::temp_dir().join(format!("aoc-verify-{name}-{}", std::process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(dir.join("input")).unwrap();
+	fs::write(
+		dir.join("aoc.toml"),
+		"year = 2021\nanswers = \"answers.toml\"\nprogress = \"progress.toml\"\n",
+	)
+	.unwrap();
+	let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("../2021/input/examples/day_five_1");
+	fs::copy(example, dir.join("input/day_five")).unwrap();
+	let mut recorded = Answers::default();
+	for (part, answer) in (1..).zip(answers) {
+		recorded.set(5, part, "day_five", answer.to_string());
+	}
+	recorded.save(dir.join("answers.toml")).unwrap();
+
+	let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+		.args(["--year", "2021", "verify"])
+		.env("AOC_CONFIG", dir.join("aoc.toml"))
+		.env_remove("AOC_INPUT_DIR")
+		.env_remove("AOC_PLUGIN_DIR")
+		.env_remove("RUST_BACKTRACE")
+		.output()
+		.unwrap();
+	fs::remove_dir_all(dir).unwrap();
+	output
+}
+
+#[test]
+fn passes_when_the_answers_match() {
+	let output = verify("match", ["5", "12"]);
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+}
+
+#[test]
+fn fails_on_a_mismatch() {
+	let output = verify("mismatch", ["5", "13"]);
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stdout).contains("mismatch"));
+	let stderr = String::from_utf8_lossy(&output.stderr);
+	assert!(stderr.contains("1 answer(s) don't match"), "{stderr}");
+}
@@ -0,0 +1,18 @@
+use std::{env, path::Path};
+
+fn main() {
+	let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+	let root = Path::new(&root);
+	let config = cbindgen::Config::from_file(root.join("cbindgen.toml")).unwrap();
+	// Only rewritten when it changes, so the header doesn't trigger rebuilds of C code on its own
+	cbindgen::Builder::new()
+		.with_crate(root)
+		.with_config(config)
+		.generate()
+		.expect("couldn't generate the C header")
+		.write_to_file(root.join("include/aoc.h"));
+	// Tests compile C programs for the same target
+	println!("cargo:rustc-env=TARGET={}", env::var("TARGET").unwrap());
+	println!("cargo:rerun-if-changed=src");
+	println!("cargo:rerun-if-changed=cbindgen.toml");
+}
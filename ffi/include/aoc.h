@@ -0,0 +1,81 @@
+/* Generated from ffi/src/lib.rs by its build script, don't edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of a call, anything but `AOC_STATUS_OK` comes with a message.
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  /**
+   * A pointer that has to be set is null
+   */
+  AOC_STATUS_NULL_ARGUMENT,
+  /**
+   * There are no solutions for the year
+   */
+  AOC_STATUS_UNKNOWN_YEAR,
+  /**
+   * The day hasn't been solved yet, or doesn't exist
+   */
+  AOC_STATUS_UNKNOWN_DAY,
+  /**
+   * The input isn't UTF-8
+   */
+  AOC_STATUS_INVALID_INPUT,
+  /**
+   * Parsing or solving a part returned an error
+   */
+  AOC_STATUS_FAILED,
+  /**
+   * Parsing or solving a part panicked
+   */
+  AOC_STATUS_PANICKED,
+} AocStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves both parts of `day` of `year`, or of the latest year when `year` is 0, from
+ * `input_len` bytes of UTF-8 at `input`.
+ *
+ * On success `*part_one` and `*part_two` are set to the answers. Otherwise they're set to null,
+ * and `*error` to a message unless `error` is null. Returned strings are owned by the caller, who
+ * frees them with `aoc_string_free`.
+ *
+ * # Safety
+ *
+ * `input` has to point to `input_len` readable bytes, it may only be null when `input_len` is 0.
+ * `part_one`, `part_two` and `error` have to be valid to write a pointer to, or null.
+ */
+enum AocStatus aoc_solve(uint16_t year,
+                         uint8_t day,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char **part_one,
+                         char **part_two,
+                         char **error);
+
+/**
+ * Frees a string returned by any `aoc_` function, null is ignored.
+ *
+ * # Safety
+ *
+ * `string` has to come from this library, and can't be used once freed.
+ */
+void aoc_string_free(char *string);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* AOC_H */
@@ -0,0 +1,138 @@
+use std::{
+	ffi::{c_char, CString},
+	panic::{self, AssertUnwindSafe},
+	ptr, slice, str,
+};
+
+use aoc::panic_message;
+use aoc_years::YEARS;
+
+/// Outcome of a call, anything but `AOC_STATUS_OK` comes with a message.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+	Ok = 0,
+	/// A pointer that has to be set is null
+	NullArgument,
+	/// There are no solutions for the year
+	UnknownYear,
+	/// The day hasn't been solved yet, or doesn't exist
+	UnknownDay,
+	/// The input isn't UTF-8
+	InvalidInput,
+	/// Parsing or solving a part returned an error
+	Failed,
+	/// Parsing or solving a part panicked
+	Panicked,
+}
+
+// Why a call failed, before it's handed over to C
+struct Failure(AocStatus, String);
+
+/// Solves both parts of `day` of `year`, or of the latest year when `year` is 0, from
+/// `input_len` bytes of UTF-8 at `input`.
+///
+/// On success `*part_one` and `*part_two` are set to the answers. Otherwise they're set to null,
+/// and `*error` to a message unless `error` is null. Returned strings are owned by the caller, who
+/// frees them with `aoc_string_free`.
+///
+/// # Safety
+///
+/// `input` has to point to `input_len` readable bytes, it may only be null when `input_len` is 0.
+/// `part_one`, `part_two` and `error` have to be valid to write a pointer to, or null.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+	year: u16,
+	day: u8,
+	input: *const u8,
+	input_len: usize,
+	part_one: *mut *mut c_char,
+	part_two: *mut *mut c_char,
+	error: *mut *mut c_char,
+) -> AocStatus {
+	for answer in [part_one, part_two] {
+		if !answer.is_null() {
+			*answer = ptr::null_mut();
+		}
+	}
+	if !error.is_null() {
+		*error = ptr::null_mut();
+	}
+	let solved = if part_one.is_null() || part_two.is_null() || (input.is_null() && input_len > 0) {
+		Err(Failure(
+			AocStatus::NullArgument,
+			String::from("the input and both answers have to be set"),
+		))
+	} else {
+		let input = match input_len {
+			0 => &[][..],
+			_ => slice::from_raw_parts(input, input_len),
+		};
+		panic::catch_unwind(AssertUnwindSafe(|| solve(year, day, input))).unwrap_or_else(|panic| {
+			Err(Failure(
+				AocStatus::Panicked,
+				format!("panicked: {}", panic_message(&*panic)),
+			))
+		})
+	};
+	match solved.and_then(|[one, two]| Ok([c_string(one)?, c_string(two)?])) {
+		Ok([one, two]) => {
+			*part_one = one.into_raw();
+			*part_two = two.into_raw();
+			AocStatus::Ok
+		}
+		Err(Failure(status, message)) => {
+			if !error.is_null() {
+				*error = CString::new(message.replace('\0', " "))
+					.unwrap_or_default()
+					.into_raw();
+			}
+			status
+		}
+	}
+}
+
+/// Frees a string returned by any `aoc_` function, null is ignored.
+///
+/// # Safety
+///
+/// `string` has to come from this library, and can't be used once freed.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_string_free(string: *mut c_char) {
+	if !string.is_null() {
+		drop(CString::from_raw(string));
+	}
+}
+
+fn solve(year: u16, day: u8, input: &[u8]) -> Result<[String; 2], Failure> {
+	let year = match year {
+		0 => YEARS[YEARS.len() - 1],
+		_ => YEARS
+			.iter()
+			.copied()
+			.find(|y| y.year == year)
+			.ok_or_else(|| {
+				Failure(
+					AocStatus::UnknownYear,
+					format!("there are no solutions for {year}"),
+				)
+			})?,
+	};
+	let day = year
+		.day(day)
+		.map_err(|e| Failure(AocStatus::UnknownDay, e.to_string()))?;
+	let input = str::from_utf8(input)
+		.map_err(|e| Failure(AocStatus::InvalidInput, format!("input isn't UTF-8: {e}")))?;
+	day
+		.solve(input)
+		.map_err(|e| Failure(AocStatus::Failed, format!("{e:#}")))
+}
+
+fn c_string(answer: String) -> Result<CString, Failure> {
+	CString::new(answer).map_err(|_| {
+		Failure(
+			AocStatus::Failed,
+			String::from("the answer contains a NUL byte"),
+		)
+	})
+}
@@ -0,0 +1,76 @@
+/* Exercises the C API the way outside tooling uses it, exits with the number of failed checks. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void check(int ok, const char *condition, int line) {
+	if (!ok) {
+		fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, line, condition);
+		failures++;
+	}
+}
+
+#define CHECK(condition) check((condition), #condition, __LINE__)
+
+static const char EXAMPLE[] =
+	"1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+/* Solves `input`, expecting `status` and an error message containing `message` */
+static void check_failure(uint16_t year, uint8_t day, const char *input,
+                          AocStatus status, const char *message) {
+	char *one = NULL, *two = NULL, *error = NULL;
+	AocStatus got = aoc_solve(year, day, (const uint8_t *)input, strlen(input),
+	                          &one, &two, &error);
+	CHECK(got == status);
+	CHECK(one == NULL && two == NULL);
+	CHECK(error != NULL && strstr(error, message) != NULL);
+	if (error != NULL && strstr(error, message) == NULL) {
+		fprintf(stderr, "unexpected error: %s\n", error);
+	}
+	aoc_string_free(error);
+}
+
+static void solves_both_parts(uint16_t year) {
+	char *one = NULL, *two = NULL, *error = NULL;
+	AocStatus status = aoc_solve(year, 1, (const uint8_t *)EXAMPLE,
+	                             strlen(EXAMPLE), &one, &two, &error);
+	CHECK(status == AOC_STATUS_OK);
+	CHECK(error == NULL);
+	CHECK(one != NULL && strcmp(one, "24000") == 0);
+	CHECK(two != NULL && strcmp(two, "45000") == 0);
+	aoc_string_free(one);
+	aoc_string_free(two);
+}
+
+int main(void) {
+	solves_both_parts(2022);
+	/* The latest year */
+	solves_both_parts(0);
+
+	check_failure(1999, 1, EXAMPLE, AOC_STATUS_UNKNOWN_YEAR,
+	              "there are no solutions for 1999");
+	check_failure(2022, 25, EXAMPLE, AOC_STATUS_UNKNOWN_DAY,
+	              "day 25 of 2022 has not been solved yet");
+	check_failure(2022, 1, "\xff\n", AOC_STATUS_INVALID_INPUT, "isn't UTF-8");
+	check_failure(2022, 1, "lots\n", AOC_STATUS_FAILED, "invalid digit");
+	/* Any character but brackets, commas and digits is unreachable */
+	check_failure(2022, 13, "[a]\n[1]\n", AOC_STATUS_PANICKED, "panicked");
+
+	/* Answers have to go somewhere, but the error can be ignored */
+	char *one = NULL;
+	CHECK(aoc_solve(2022, 1, (const uint8_t *)EXAMPLE, strlen(EXAMPLE), &one,
+	                NULL, NULL) == AOC_STATUS_NULL_ARGUMENT);
+	CHECK(one == NULL);
+	CHECK(aoc_solve(2022, 1, NULL, 3, &one, &one, NULL) ==
+	      AOC_STATUS_NULL_ARGUMENT);
+	aoc_string_free(NULL);
+
+	if (failures == 0) {
+		printf("all checks passed\n");
+	}
+	return failures;
+}
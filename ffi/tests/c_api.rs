@@ -0,0 +1,52 @@
+#![cfg(unix)]
+
+use std::{env, path::Path, process::Command};
+
+// Compiles tests/c/solve.c against include/aoc.h and the cdylib built next to this test, then runs it
+#[test]
+fn c_program_solves_days() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+	let program = dir.join(format!("c_api_solve{}", env::consts::EXE_SUFFIX));
+
+	let compiler = cc::Build::new()
+		.target(env!("TARGET"))
+		.host(env!("TARGET"))
+		.opt_level(0)
+		.debug(false)
+		.cargo_metadata(false)
+		.warnings(true)
+		.extra_warnings(true)
+		.warnings_into_errors(true)
+		.get_compiler();
+	let output = compiler
+		.to_command()
+		.arg(root.join("tests/c/solve.c"))
+		.arg("-I")
+		.arg(root.join("include"))
+		.arg("-L")
+		.arg(&dir)
+		.arg("-laoc_ffi")
+		.arg(format!("-Wl,-rpath,{}", dir.display()))
+		.arg("-o")
+		.arg(&program)
+		.output()
+		.unwrap();
+	assert!(
+		output.status.success(),
+		"compiling failed:\n{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+
+	let output = Command::new(&program).output().unwrap();
+	assert!(
+		output.status.success(),
+		"{} check(s) failed:\n{}",
+		output.status.code().unwrap_or(-1),
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(
+		String::from_utf8_lossy(&output.stdout),
+		"all checks passed\n"
+	);
+}
@@ -0,0 +1,41 @@
+use anyhow::{bail, Result};
+use aoc::Solution;
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+	type Input = Vec<u32>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		let depths = input
+			.lines()
+			.map(str::parse)
+			.collect::<Result<Vec<_>, _>>()?;
+		if depths.is_empty() {
+			bail!("there are no depths to compare");
+		}
+		Ok(depths)
+	}
+
+	fn part_one(input: &Self::Input) -> Result<usize> {
+		Ok(increases(input, 1))
+	}
+
+	fn part_two(input: &Self::Input) -> Result<usize> {
+		// Neighbouring windows share all but their first and last depth
+		Ok(increases(input, 3))
+	}
+}
+
+// How many depths are deeper than the one `gap` places before them
+fn increases(depths: &[u32], gap: usize) -> usize {
+	depths
+		.iter()
+		.zip(depths.iter().skip(gap))
+		.filter(|(before, after)| after > before)
+		.count()
+}
+
+aoc::export_plugin!(2021, 1, DayOne);
@@ -0,0 +1,107 @@
+use std::{env, fs, path::PathBuf};
+
+use aoc::plugin::{self, Descriptor, ABI_VERSION};
+use aoc_plugin_example::DayOne;
+
+const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+// Built next to the test binary, since the library is also a cdylib
+fn library() -> PathBuf {
+	env::current_exe().unwrap().with_file_name(format!(
+		"{}aoc_plugin_example{}",
+		env::consts::DLL_PREFIX,
+		env::consts::DLL_SUFFIX
+	))
+}
+
+#[test]
+fn registers_the_day_of_a_plugin() {
+	let plugin = plugin::load(&library()).unwrap();
+	assert_eq!((plugin.year(), plugin.day()), (2021, 1));
+
+	let years = plugin::register(&[&advent_of_code_2021::YEAR], vec![plugin]).unwrap();
+	let days = years[0].days.iter().map(|d| d.day).collect::<Vec<_>>();
+	assert_eq!(days, [1, 5]);
+	let day = years[0].day(1).unwrap();
+	assert_eq!(day.name, "one");
+	assert_eq!(day.plugin().unwrap().path(), library());
+	assert_eq!(day.solve(EXAMPLE).unwrap(), ["7", "5"]);
+	assert!(years[0].day(5).unwrap().plugin().is_none());
+}
+
+#[test]
+fn passes_on_errors() {
+	let plugin = plugin::load(&library()).unwrap();
+	let years = plugin::register(&[&advent_of_code_2021::YEAR], vec![plugin]).unwrap();
+	let day = years[0].day(1).unwrap();
+	assert_eq!(
+		day.solve("").unwrap_err().to_string(),
+		"there are no depths to compare"
+	);
+	assert_eq!(
+		day.solve("1\ndeep\n").unwrap_err().to_string(),
+		"invalid digit found in string"
+	);
+}
+
+#[test]
+fn refuses_days_it_cannot_register() {
+	let error = plugin::register(&[], vec![plugin::load(&library()).unwrap()]).unwrap_err();
+	assert!(
+		error
+			.to_string()
+			.ends_with("solves day 1 of 2021, but there are no solutions for 2021 to add it to"),
+		"{error}"
+	);
+
+	let plugins = vec![
+		plugin::load(&library()).unwrap(),
+		plugin::load(&library()).unwrap(),
+	];
+	let error = plugin::register(&[&advent_of_code_2021::YEAR], plugins).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		format!(
+			"plugin {0} solves day 1 of 2021, which plugin {0} already solves",
+			library().display()
+		)
+	);
+}
+
+#[test]
+fn checks_the_descriptor() {
+	let mut descriptor = Descriptor::new::<DayOne>(2021, 1);
+	plugin::check(&descriptor).unwrap();
+
+	descriptor.abi_version = ABI_VERSION + 1;
+	assert_eq!(
+		plugin::check(&descriptor).unwrap_err().to_string(),
+		format!(
+			"it was built for plugin ABI version {}, but this aoc only loads version {ABI_VERSION}, \
+			 rebuild it against the aoc crate of this workspace",
+			ABI_VERSION + 1
+		)
+	);
+
+	let descriptor = Descriptor::new::<DayOne>(2021, 26);
+	assert_eq!(
+		plugin::check(&descriptor).unwrap_err().to_string(),
+		"there is no day 26 in Advent of Code"
+	);
+}
+
+#[test]
+fn fails_to_load_anything_else() {
+	let path = env::temp_dir().join(format!(
+		"aoc-not-a-plugin-{}{}",
+		std::process::id(),
+		env::consts::DLL_SUFFIX
+	));
+	fs::write(&path, "not a library").unwrap();
+	let error = plugin::load(&path).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		format!("couldn't load plugin {}", path.display())
+	);
+	fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,4 @@
+use aoc::Year;
+
+/// Every year crate in the workspace, oldest first. A new year crate is added here.
+pub static YEARS: &[&Year] = &[&advent_of_code_2021::YEAR, &advent_of_code_2022::YEAR];